@@ -0,0 +1,40 @@
+use std::time::Instant;
+
+use enop_rs::{EngineeringOptimizationProblem, FastEvaluator};
+use mahf::ExecResult;
+
+const EVALUATIONS: usize = 100_000;
+
+fn main() -> ExecResult<()> {
+    let problem = EngineeringOptimizationProblem::try_welded_beam_design()?;
+    let solutions: Vec<_> = (0..EVALUATIONS)
+        .map(|_| problem.random_solution(&mut rand::thread_rng()))
+        .collect();
+
+    // Compares the per-solution overhead of the full and the lean evaluator.
+    let evaluator = problem.evaluator();
+    let start = Instant::now();
+    for solution in &solutions {
+        evaluator.evaluate_one(solution);
+    }
+    report(
+        "EngineeringOptimizationEvaluator",
+        start.elapsed().as_secs_f64(),
+    );
+
+    let evaluator = FastEvaluator::new(&problem)?;
+    let start = Instant::now();
+    for solution in &solutions {
+        evaluator.evaluate_one(solution);
+    }
+    report("FastEvaluator", start.elapsed().as_secs_f64());
+
+    Ok(())
+}
+
+fn report(name: &str, seconds: f64) {
+    println!(
+        "{name}: {seconds:.3} s, {:.1} µs per evaluation",
+        seconds * 1e6 / EVALUATIONS as f64
+    );
+}
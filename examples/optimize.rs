@@ -0,0 +1,74 @@
+use std::sync::Mutex;
+
+use enop_rs::{
+    BestFeasibility, DebAggregatedEvaluator, DebAggregatedProblem, EngineeringOptimizationProblem,
+};
+use mahf::{conditions, heuristics::de, ExecResult, Problem};
+
+fn main() -> ExecResult<()> {
+    // Constraints are handled by Deb's rules, which are folded into the objective value,
+    // so every feasible solution is better than every infeasible one.
+    // The offset has to exceed the objective value of every feasible solution.
+    // The cost of a welded beam grows with every variable, so it is bounded by the cost at
+    // the upper corner of the domain.
+    let inner = EngineeringOptimizationProblem::try_welded_beam_design()?;
+    let offset = inner
+        .evaluator()
+        .evaluate_full(&inner.upper_bounds())
+        .objective;
+    let problem = DebAggregatedProblem::new(inner, offset);
+
+    // The feasibility of the best individual is tracked in the state.
+    let best_so_far = Mutex::new(f64::INFINITY);
+    let evaluator = DebAggregatedEvaluator::from_evaluator(
+        problem
+            .inner()
+            .evaluator()
+            .with_feasibility_tracking(true)
+            .on_evaluate(move |_, fitness| {
+                // The callback receives aggregated values, which are the objective value of
+                // feasible solutions and the offset plus the violation otherwise.
+                let mut best = best_so_far.lock().unwrap();
+                if fitness < *best {
+                    *best = fitness;
+                    if fitness > offset {
+                        println!("improved: violation {:e}", fitness - offset);
+                    } else {
+                        println!("improved: objective {fitness:.6}");
+                    }
+                }
+            }),
+        problem.offset(),
+    );
+
+    let config = de::real_de(
+        de::RealProblemParameters {
+            population_size: 40,
+            y: 1,
+            f: 0.5,
+            pc: 0.9,
+        },
+        conditions::LessThanN::iterations(500),
+    )?;
+    let state = config.optimize(&problem, evaluator)?;
+
+    println!("Problem: {}", problem.name());
+    if let Some(best) = state.best_individual() {
+        println!("Best solution: {:?}", best.solution());
+    }
+    if let Some(best) = state
+        .try_borrow::<BestFeasibility>()
+        .ok()
+        .and_then(|best| best.0)
+    {
+        println!(
+            "Best aggregated objective value: {} (feasible: {}, violation: {:e})",
+            best.objective, best.feasible, best.total_violation
+        );
+    }
+    if let Some(optimum) = problem.inner().global_optimum_value() {
+        println!("Known optimum: {optimum}");
+    }
+
+    Ok(())
+}
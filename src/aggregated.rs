@@ -0,0 +1,227 @@
+//! Constraint handling by folding Deb's rules into a single objective value.
+
+use std::{convert::Infallible, ops::Range};
+
+use log::warn;
+use mahf::{
+    problems::{Evaluate, KnownOptimumProblem, LimitedVectorProblem, VectorProblem},
+    Individual, Problem, SingleObjective, State,
+};
+use pyo3::Python;
+
+use crate::{
+    ConstraintViolation, DynEngineeringProblem, EngineeringOptimizationEvaluator,
+    EngineeringOptimizationProblem, Feasibility,
+};
+
+/// An [`EngineeringOptimizationProblem`] whose objective value already encodes
+/// [Deb's rules](crate::deb_compare), so unconstrained mahf heuristics handle the
+/// constraints without a custom comparator.
+///
+/// # Mapping
+///
+/// Given the `offset` of [`Self::new`], a solution with objective value `f` and total
+/// violation `v` is assigned
+///
+/// - `min(f, offset)` if it is feasible, i.e. `v` is zero, and
+/// - `offset + v` otherwise.
+///
+/// Since `v` is positive for infeasible solutions, every feasible solution is better than
+/// every infeasible one, feasible solutions are ordered by objective, and infeasible
+/// solutions are ordered by violation, exactly as by Deb's rules.
+/// This only holds for feasible objective values below `offset`, so it should be an upper
+/// bound of the objective, e.g. the objective value of a poor feasible solution.
+/// Feasible objective values at or above `offset` are clamped to it, which loses their
+/// relative order, and a warning is logged once per evaluator.
+///
+/// Objective values are negated for maximization problems before the mapping.
+#[derive(Clone, Debug)]
+pub struct DebAggregatedProblem {
+    inner: EngineeringOptimizationProblem,
+    offset: f64,
+}
+
+impl DebAggregatedProblem {
+    pub fn new(inner: EngineeringOptimizationProblem, offset: f64) -> Self {
+        Self { inner, offset }
+    }
+
+    pub fn offset(&self) -> f64 {
+        self.offset
+    }
+
+    pub fn inner(&self) -> &EngineeringOptimizationProblem {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> EngineeringOptimizationProblem {
+        self.inner
+    }
+
+    /// Creates an evaluator applying the mapping described in the
+    /// [type-level documentation](Self).
+    pub fn evaluator(&self) -> DebAggregatedEvaluator {
+        DebAggregatedEvaluator::from_evaluator(self.inner.evaluator(), self.offset)
+    }
+}
+
+impl Problem for DebAggregatedProblem {
+    type Encoding = Vec<f64>;
+    type Objective = SingleObjective;
+
+    fn name(&self) -> &str {
+        Problem::name(&self.inner)
+    }
+}
+
+/// The known optimum is feasible, so it is mapped to itself if it is below the offset.
+///
+/// Without a known optimum, the default objective is returned like for the inner problem,
+/// as clamping it to the offset would make every feasible solution optimal.
+impl KnownOptimumProblem for DebAggregatedProblem {
+    fn known_optimum(&self) -> SingleObjective {
+        if self.inner.global_optimum_value().is_none() {
+            return SingleObjective::default();
+        }
+        let optimum = self.inner.known_optimum().value().min(self.offset);
+        SingleObjective::try_from(optimum).unwrap_or_default()
+    }
+}
+
+impl VectorProblem for DebAggregatedProblem {
+    type Element = f64;
+
+    fn dimension(&self) -> usize {
+        VectorProblem::dimension(&self.inner)
+    }
+}
+
+impl LimitedVectorProblem for DebAggregatedProblem {
+    fn domain(&self) -> Vec<Range<Self::Element>> {
+        LimitedVectorProblem::domain(&self.inner)
+    }
+}
+
+/// Evaluator for [`DebAggregatedProblem`]s.
+///
+/// Each solution is evaluated with
+/// [`EngineeringOptimizationEvaluator::evaluate_feasibility`], so the
+/// [equality tolerance](EngineeringOptimizationEvaluator::equality_tolerance), clamping, and
+/// rounding of the inner evaluator apply, while its penalty is ignored.
+/// The budget, callback, and constraint violation, feasibility, and best tracking of the
+/// inner evaluator apply as well, where objective values are the aggregated ones.
+/// Repairs are not supported, as repaired candidates would be evaluated without the
+/// aggregation.
+#[derive(Clone)]
+pub struct DebAggregatedEvaluator {
+    inner: EngineeringOptimizationEvaluator,
+    offset: f64,
+    warned: bool,
+}
+
+impl DebAggregatedEvaluator {
+    /// Wraps `inner`, keeping its configuration.
+    ///
+    /// A repair strategy set with [`EngineeringOptimizationEvaluator::with_repair`] is
+    /// unsupported and disabled with a warning.
+    pub fn from_evaluator(mut inner: EngineeringOptimizationEvaluator, offset: f64) -> Self {
+        if inner.repair.take().is_some() {
+            warn!(
+                "repairing solutions of `{}` is not supported with aggregated constraints \
+                and disabled",
+                inner.name
+            );
+        }
+        Self {
+            inner,
+            offset,
+            warned: false,
+        }
+    }
+
+    pub fn into_inner(self) -> EngineeringOptimizationEvaluator {
+        self.inner
+    }
+
+    /// Evaluates the aggregated objective value of `solution`.
+    pub fn evaluate_one(&mut self, solution: &[f64]) -> f64 {
+        let feasibility = self.inner.evaluate_feasibility(solution);
+        aggregate(&self.inner, self.offset, &mut self.warned, feasibility)
+    }
+}
+
+/// Applies the mapping of [`DebAggregatedProblem`], warning once via `warned` if a
+/// feasible objective value is clamped.
+fn aggregate(
+    inner: &EngineeringOptimizationEvaluator,
+    offset: f64,
+    warned: &mut bool,
+    feasibility: Feasibility,
+) -> f64 {
+    if !feasibility.feasible {
+        return offset + feasibility.total_violation;
+    }
+    if feasibility.objective >= offset && !*warned {
+        warn!(
+            "feasible objective value {} of `{}` is not below the offset {offset}, \
+            so feasible solutions are no longer ordered by objective",
+            feasibility.objective, inner.name
+        );
+        *warned = true;
+    }
+    feasibility.objective.min(offset)
+}
+
+impl Evaluate for DebAggregatedEvaluator {
+    type Problem = DebAggregatedProblem;
+
+    fn evaluate(
+        &mut self,
+        _problem: &Self::Problem,
+        state: &mut State<Self::Problem>,
+        individuals: &mut [Individual<Self::Problem>],
+    ) {
+        let (offset, warned) = (self.offset, &mut self.warned);
+        let Ok(()) = self
+            .inner
+            .evaluate_individuals(state, individuals, |inner, solutions| {
+                Ok::<_, Infallible>(Python::with_gil(|_| {
+                    solutions
+                        .iter()
+                        .map(|solution| {
+                            let feasibility = inner.evaluate_feasibility(solution);
+                            let violation = ConstraintViolation(feasibility.total_violation);
+                            (
+                                aggregate(inner, offset, warned, feasibility),
+                                Some(violation),
+                            )
+                        })
+                        .collect()
+                }))
+            });
+    }
+}
+
+impl DynEngineeringProblem for DebAggregatedEvaluator {
+    fn name(&self) -> &str {
+        &self.inner.name
+    }
+
+    fn dimension(&self) -> usize {
+        self.inner.domain.len()
+    }
+
+    fn domain(&self) -> Vec<Range<f64>> {
+        self.inner.domain.to_vec()
+    }
+
+    /// Evaluates the aggregated objective value of `solution`, see [`Self::evaluate_one`].
+    ///
+    /// Since this only borrows the evaluator, clamped objective values are warned about on
+    /// every call until [`Self::evaluate_one`] or [`Evaluate::evaluate`] logged the warning.
+    fn evaluate_one(&self, solution: &[f64]) -> f64 {
+        let mut warned = self.warned;
+        let feasibility = self.inner.evaluate_feasibility(solution);
+        aggregate(&self.inner, self.offset, &mut warned, feasibility)
+    }
+}
@@ -0,0 +1,15 @@
+//! Integration with the [`argmin`](::argmin) optimization framework.
+
+use ::argmin::core::{CostFunction, Error};
+
+use crate::EngineeringOptimizationEvaluator;
+
+/// Evaluates the objective value using [`EngineeringOptimizationEvaluator::evaluate_one`].
+impl CostFunction for EngineeringOptimizationEvaluator {
+    type Param = Vec<f64>;
+    type Output = f64;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(self.evaluate_one(param))
+    }
+}
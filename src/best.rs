@@ -0,0 +1,122 @@
+//! Tracking the best solutions across evaluations.
+
+use std::{
+    cmp::Ordering,
+    sync::{MutexGuard, PoisonError},
+};
+
+use mahf::SingleObjective;
+
+use crate::{deb_compare, ConstraintViolation, EngineeringOptimizationEvaluator};
+
+/// A solution tracked by [`EngineeringOptimizationEvaluator::with_best_tracking`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BestSolution {
+    /// The solution as stored in the individual, i.e. after repair but before clamping
+    /// or rounding.
+    pub solution: Vec<f64>,
+    /// The objective value assigned to the individual, which includes the penalty if
+    /// enabled.
+    pub objective: f64,
+    /// Whether the total violation is zero.
+    pub feasible: bool,
+    /// The total [`ConstraintViolation`].
+    pub total_violation: f64,
+}
+
+impl BestSolution {
+    fn violation(&self) -> ConstraintViolation {
+        ConstraintViolation(self.total_violation)
+    }
+}
+
+/// The best solutions shared between clones of an evaluator.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct BestSoFar {
+    feasible: Option<BestSolution>,
+    overall: Option<BestSolution>,
+}
+
+impl EngineeringOptimizationEvaluator {
+    /// Enables or disables tracking the best feasible and best overall solution across
+    /// all evaluations, see [`Self::best_feasible`] and [`Self::best_overall`].
+    ///
+    /// Like [`Self::with_constraint_violation`], this requires an additional Python call
+    /// per individual and is disabled by default.
+    /// The tracked solutions are shared between clones of the evaluator.
+    pub fn with_best_tracking(mut self, enabled: bool) -> Self {
+        self.track_best = enabled;
+        self
+    }
+
+    /// Returns the feasible solution with the best objective value evaluated so far.
+    pub fn best_feasible(&self) -> Option<BestSolution> {
+        self.lock_best().feasible.clone()
+    }
+
+    /// Returns the best solution evaluated so far, regardless of its feasibility.
+    ///
+    /// If a constraint-aware mode is enabled, i.e. [`Self::with_constraint_violation`],
+    /// [`Self::with_feasibility_tracking`], [`Self::with_repair`], or a penalty, solutions
+    /// are ordered by [Deb's rules](deb_compare), and otherwise by objective value only.
+    pub fn best_overall(&self) -> Option<BestSolution> {
+        self.lock_best().overall.clone()
+    }
+
+    /// Forgets the best solutions tracked so far.
+    pub fn reset_best(&self) {
+        *self.lock_best() = BestSoFar::default();
+    }
+
+    /// Updates the best solutions with an evaluated `solution`.
+    pub(crate) fn record_best(
+        &self,
+        solution: &[f64],
+        objective: f64,
+        violation: Option<ConstraintViolation>,
+    ) {
+        let violation = violation.unwrap_or(ConstraintViolation(f64::INFINITY));
+        let candidate = || BestSolution {
+            solution: solution.to_vec(),
+            objective,
+            feasible: violation.is_feasible(),
+            total_violation: violation.0,
+        };
+        let constraint_aware = self.constraint_aware();
+        let mut best = self.lock_best();
+
+        let improves_feasible = violation.is_feasible()
+            && best
+                .feasible
+                .as_ref()
+                .is_none_or(|best| objective < best.objective);
+        if improves_feasible {
+            best.feasible = Some(candidate());
+        }
+
+        let improves_overall = best.overall.as_ref().is_none_or(|best| {
+            if constraint_aware {
+                let objective = SingleObjective::try_from(objective).unwrap_or_default();
+                let best_objective = SingleObjective::try_from(best.objective).unwrap_or_default();
+                deb_compare((objective, violation), (best_objective, best.violation()))
+                    == Ordering::Less
+            } else {
+                objective < best.objective
+            }
+        });
+        if improves_overall {
+            best.overall = Some(candidate());
+        }
+    }
+
+    /// Locks the best solutions, ignoring poisoning on purpose.
+    ///
+    /// A thread that panicked while holding the lock may have updated `feasible` but not
+    /// `overall`, so `overall` can miss the latest feasible improvement.
+    /// Each field is still replaced by a complete entry, which is why both remain valid
+    /// solutions that were evaluated at some point.
+    fn lock_best(&self) -> MutexGuard<'_, BestSoFar> {
+        self.best.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
@@ -0,0 +1,119 @@
+//! Evaluation through a reused NumPy buffer.
+
+use std::{convert::Infallible, ops::Range};
+
+use log::warn;
+use mahf::{problems::Evaluate, Individual, State};
+use numpy::PyArray1;
+use pyo3::{Py, Python};
+
+use crate::{
+    ConstraintViolation, DynEngineeringProblem, EngineeringOptimizationEvaluator,
+    EngineeringOptimizationProblem,
+};
+
+/// An evaluator that copies every solution into a single preallocated NumPy array,
+/// instead of allocating a new one per evaluation.
+///
+/// This targets hot loops of many single-solution evaluations, where batches can't
+/// be vectorized anyway.
+///
+/// # Thread safety
+///
+/// The buffer is overwritten in place, so evaluating requires `&mut self` and the
+/// evaluator is not [`Clone`].
+/// Problems that keep a reference to the array passed to them, e.g. to cache the last
+/// solution, observe it changing with the next evaluation.
+pub struct BufferedEvaluator {
+    inner: EngineeringOptimizationEvaluator,
+    buffer: Py<PyArray1<f64>>,
+}
+
+impl BufferedEvaluator {
+    pub fn new(problem: &EngineeringOptimizationProblem) -> Self {
+        Self::from_evaluator(problem.evaluator())
+    }
+
+    /// Wraps `inner`, keeping its configuration.
+    pub fn from_evaluator(inner: EngineeringOptimizationEvaluator) -> Self {
+        let buffer = Python::with_gil(|py| PyArray1::zeros(py, inner.domain.len(), false).into());
+        Self { inner, buffer }
+    }
+
+    pub fn into_inner(self) -> EngineeringOptimizationEvaluator {
+        self.inner
+    }
+
+    /// Evaluates the objective value of a single `solution`, see
+    /// [`EngineeringOptimizationEvaluator::evaluate_one`].
+    pub fn evaluate_one(&mut self, solution: &[f64]) -> f64 {
+        let solution = self.inner.prepare_solution(solution);
+        Python::with_gil(|py| evaluate_buffered(&self.inner, py, &self.buffer, &solution).0)
+    }
+}
+
+/// Evaluates a `solution` returned by
+/// [`EngineeringOptimizationEvaluator::prepare_solution`] by copying it into `buffer`.
+fn evaluate_buffered(
+    inner: &EngineeringOptimizationEvaluator,
+    py: Python,
+    buffer: &Py<PyArray1<f64>>,
+    solution: &[f64],
+) -> (f64, Option<ConstraintViolation>) {
+    if solution.len() != inner.domain.len() {
+        // Rejected with a warning without touching the buffer.
+        return inner.evaluate_prepared(py, solution);
+    }
+
+    let buffer = buffer.as_ref(py);
+    match buffer.readwrite().as_slice_mut() {
+        Ok(slice) => slice.copy_from_slice(solution),
+        Err(err) => {
+            warn!("failed to write the evaluation buffer: {err}");
+            return inner.evaluate_prepared(py, solution);
+        }
+    }
+    inner.evaluate_array(py, buffer)
+}
+
+impl Evaluate for BufferedEvaluator {
+    type Problem = EngineeringOptimizationProblem;
+
+    fn evaluate(
+        &mut self,
+        _problem: &Self::Problem,
+        state: &mut State<Self::Problem>,
+        individuals: &mut [Individual<Self::Problem>],
+    ) {
+        let buffer = &self.buffer;
+        let Ok(()) = self
+            .inner
+            .evaluate_individuals(state, individuals, |inner, solutions| {
+                Ok::<_, Infallible>(Python::with_gil(|py| {
+                    solutions
+                        .iter()
+                        .map(|solution| evaluate_buffered(inner, py, buffer, solution))
+                        .collect()
+                }))
+            });
+    }
+}
+
+impl DynEngineeringProblem for BufferedEvaluator {
+    fn name(&self) -> &str {
+        &self.inner.name
+    }
+
+    fn dimension(&self) -> usize {
+        self.inner.domain.len()
+    }
+
+    fn domain(&self) -> Vec<Range<f64>> {
+        self.inner.domain.to_vec()
+    }
+
+    /// Evaluates `solution` without the buffer, which can only be written through `&mut self`.
+    fn evaluate_one(&self, solution: &[f64]) -> f64 {
+        self.inner.evaluate_one(solution)
+    }
+}
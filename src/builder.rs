@@ -0,0 +1,114 @@
+//! Fluent construction of problems and their evaluators.
+
+use std::path::PathBuf;
+
+use mahf::ExecResult;
+
+use crate::{
+    configure_python, EngineeringOptimizationEvaluator, EngineeringOptimizationProblem, RWCO_2020,
+};
+
+/// A builder for an [`EngineeringOptimizationProblem`] and a configured evaluator.
+///
+/// For example, `EngineeringOptimizationProblemBuilder::new("WeldedBeamDesignProblem").penalty(1e6).build()`
+/// loads the welded beam design problem together with a penalizing evaluator.
+#[derive(Clone, Debug)]
+pub struct EngineeringOptimizationProblemBuilder {
+    name: String,
+    module: String,
+    dim: Option<usize>,
+    penalty: Option<f64>,
+    clamp: bool,
+    venv: Option<PathBuf>,
+    seed: Option<u64>,
+}
+
+impl EngineeringOptimizationProblemBuilder {
+    /// Starts building the RWCO 2020 problem `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            module: RWCO_2020.to_string(),
+            dim: None,
+            penalty: None,
+            clamp: false,
+            venv: None,
+            seed: None,
+        }
+    }
+
+    /// Sets the problem class name.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Sets the enoppy module the problem is loaded from.
+    pub fn module(mut self, module: impl Into<String>) -> Self {
+        self.module = module.into();
+        self
+    }
+
+    /// Sets the dimension, see [`EngineeringOptimizationProblem::new_with_dim`].
+    pub fn dim(mut self, dim: usize) -> Self {
+        self.dim = Some(dim);
+        self
+    }
+
+    /// Sets the penalty factor of the evaluator, see
+    /// [`EngineeringOptimizationEvaluator::with_penalty`].
+    pub fn penalty(mut self, rho: f64) -> Self {
+        self.penalty = Some(rho);
+        self
+    }
+
+    /// Enables or disables clamping in the evaluator, see
+    /// [`EngineeringOptimizationEvaluator::clamp_to_domain`].
+    pub fn clamp(mut self, enabled: bool) -> Self {
+        self.clamp = enabled;
+        self
+    }
+
+    /// Sets a virtual environment to import enoppy from, see [`configure_python`].
+    pub fn venv(mut self, path: impl Into<PathBuf>) -> Self {
+        self.venv = Some(path.into());
+        self
+    }
+
+    /// Sets a seed for stochastic problems, see [`EngineeringOptimizationProblem::with_seed`].
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Loads the problem and creates its evaluator.
+    pub fn build(
+        self,
+    ) -> ExecResult<(
+        EngineeringOptimizationProblem,
+        EngineeringOptimizationEvaluator,
+    )> {
+        if let Some(venv) = &self.venv {
+            configure_python(venv)?;
+        }
+
+        let problem = match self.dim {
+            Some(dim) => {
+                EngineeringOptimizationProblem::load_with_dim(&self.module, &self.name, dim)?
+            }
+            None => EngineeringOptimizationProblem::from_module(&self.module, &self.name)?,
+        };
+
+        let problem = match self.seed {
+            Some(seed) => problem.with_seed(seed)?,
+            None => problem,
+        };
+
+        let mut evaluator = problem.evaluator().clamp_to_domain(self.clamp);
+        if let Some(rho) = self.penalty {
+            evaluator = evaluator.with_penalty(rho);
+        }
+
+        Ok((problem, evaluator))
+    }
+}
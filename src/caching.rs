@@ -0,0 +1,189 @@
+//! Memoization of objective values.
+
+use std::{collections::HashMap, ops::Range};
+
+use mahf::{problems::Evaluate, Individual, SingleObjective, State};
+
+use crate::{
+    DynEngineeringProblem, EngineeringOptimizationEvaluator, EngineeringOptimizationProblem,
+    Repaired,
+};
+
+/// An evaluator wrapper that caches objective values keyed on the quantized solution.
+///
+/// Two solutions share a cache entry if all their coordinates round to the same
+/// multiple of the tolerance.
+/// Non-finite coordinates and coordinates too large to be quantized only match exactly.
+/// Only cache misses are passed to the inner evaluator, which means that the
+/// [`ConstraintViolations`] it inserts into the state only cover these.
+/// Misses beyond the evaluation budget of the inner evaluator are not cached, as they are
+/// assigned infinite objective values without being evaluated.
+///
+/// If the inner evaluator [repairs](EngineeringOptimizationEvaluator::with_repair)
+/// solutions, repaired misses are replaced by their repaired solution, which is also the
+/// key of their cache entry, and [`Repaired`] covers the whole batch, where cache hits
+/// count as not repaired.
+///
+/// [`ConstraintViolations`]: crate::ConstraintViolations
+#[derive(Clone)]
+pub struct CachingEvaluator {
+    inner: EngineeringOptimizationEvaluator,
+    tolerance: f64,
+    cache: HashMap<Vec<Coordinate>, SingleObjective>,
+    hits: usize,
+    misses: usize,
+}
+
+impl CachingEvaluator {
+    /// Wraps `inner` with a cache quantizing solutions with `tolerance`.
+    ///
+    /// A non-positive `tolerance` only caches exactly equal solutions.
+    pub fn new(inner: EngineeringOptimizationEvaluator, tolerance: f64) -> Self {
+        Self {
+            inner,
+            tolerance,
+            cache: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    pub fn cache_hits(&self) -> usize {
+        self.hits
+    }
+
+    pub fn cache_misses(&self) -> usize {
+        self.misses
+    }
+
+    /// Removes all cached objective values.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+
+    pub fn into_inner(self) -> EngineeringOptimizationEvaluator {
+        self.inner
+    }
+
+    fn key(&self, solution: &[f64]) -> Vec<Coordinate> {
+        solution
+            .iter()
+            .map(|&x| {
+                let quantized = (x / self.tolerance).round();
+                // Casting would saturate, e.g. mapping NaN to the same key as zero.
+                if self.tolerance > 0.0 && quantized.abs() < i64::MAX as f64 {
+                    Coordinate::Quantized(quantized as i64)
+                } else {
+                    Coordinate::Exact(x.to_bits())
+                }
+            })
+            .collect()
+    }
+}
+
+/// A coordinate of a cache key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Coordinate {
+    /// The coordinate divided by the tolerance and rounded.
+    Quantized(i64),
+    /// The bits of a coordinate that can't be quantized, e.g. NaN or a huge value, or of
+    /// any coordinate if the tolerance is not positive.
+    Exact(u64),
+}
+
+impl Evaluate for CachingEvaluator {
+    type Problem = EngineeringOptimizationProblem;
+
+    fn evaluate(
+        &mut self,
+        problem: &Self::Problem,
+        state: &mut State<Self::Problem>,
+        individuals: &mut [Individual<Self::Problem>],
+    ) {
+        let mut missed = Vec::new();
+
+        for (i, individual) in individuals.iter_mut().enumerate() {
+            let key = self.key(individual.solution());
+            if let Some(objective) = self.cache.get(&key) {
+                individual.set_objective(*objective);
+                self.hits += 1;
+            } else {
+                missed.push((i, key));
+                self.misses += 1;
+            }
+        }
+
+        let repairs = self.inner.repair.is_some();
+        if missed.is_empty() {
+            if repairs {
+                state.insert(Repaired(vec![false; individuals.len()]));
+            }
+            return;
+        }
+
+        let mut misses: Vec<_> = missed
+            .iter()
+            .map(|&(i, _)| Individual::new_unevaluated(individuals[i].solution().clone()))
+            .collect();
+        // The inner evaluator only evaluates the misses within its remaining budget.
+        let within = self.inner.remaining_budget().unwrap_or(usize::MAX);
+        self.inner.evaluate(problem, state, &mut misses);
+
+        // The inner evaluator reports repairs in the order of the misses.
+        let repaired_misses = if repairs {
+            state.remove::<Repaired>().map(|repaired| repaired.0).ok()
+        } else {
+            None
+        };
+        let mut repaired = vec![false; individuals.len()];
+
+        for (j, ((i, key), evaluated)) in missed.into_iter().zip(misses).enumerate() {
+            let objective = *evaluated.objective();
+            // Repaired solutions are cached under their own key, as the objective value
+            // belongs to them.
+            let was_repaired = repaired_misses
+                .as_ref()
+                .is_some_and(|repaired| repaired.get(j) == Some(&true));
+            let key = if was_repaired {
+                repaired[i] = true;
+                self.key(evaluated.solution())
+            } else {
+                key
+            };
+            if j < within {
+                self.cache.insert(key, objective);
+            }
+            individuals[i] = evaluated;
+        }
+        if repairs {
+            state.insert(Repaired(repaired));
+        }
+    }
+}
+
+impl DynEngineeringProblem for CachingEvaluator {
+    fn name(&self) -> &str {
+        &self.inner.name
+    }
+
+    fn dimension(&self) -> usize {
+        self.inner.domain.len()
+    }
+
+    fn domain(&self) -> Vec<Range<f64>> {
+        self.inner.domain.to_vec()
+    }
+
+    /// Reuses cached objective values, but doesn't cache new ones or count hits and misses,
+    /// as this only borrows the evaluator.
+    fn evaluate_one(&self, solution: &[f64]) -> f64 {
+        match self.cache.get(&self.key(solution)) {
+            Some(objective) => objective.value(),
+            None => self.inner.evaluate_one(solution),
+        }
+    }
+}
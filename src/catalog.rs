@@ -0,0 +1,47 @@
+//! A serializable catalog of all available problems.
+
+use std::ops::Range;
+
+use eyre::WrapErr;
+use mahf::ExecResult;
+use serde::{Deserialize, Serialize};
+
+use crate::{EngineeringOptimizationProblem, ProblemCategory};
+
+/// The metadata of a single problem, as listed by [`export_catalog`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ProblemCatalogEntry {
+    pub name: String,
+    pub module: String,
+    pub dimension: usize,
+    pub domain: Vec<Range<f64>>,
+    pub num_inequality_constraints: usize,
+    pub num_equality_constraints: usize,
+    pub category: Option<ProblemCategory>,
+    pub global_optimum: Option<f64>,
+}
+
+impl From<&EngineeringOptimizationProblem> for ProblemCatalogEntry {
+    fn from(problem: &EngineeringOptimizationProblem) -> Self {
+        Self {
+            name: problem.name.clone(),
+            module: problem.module.clone(),
+            dimension: problem.dim,
+            domain: problem.domain.to_vec(),
+            num_inequality_constraints: problem.num_inequality,
+            num_equality_constraints: problem.num_equality,
+            category: problem.category(),
+            global_optimum: problem.global_optimum,
+        }
+    }
+}
+
+/// Loads all problems, see [`EngineeringOptimizationProblem::all`], and serializes
+/// their metadata to a JSON array of [`ProblemCatalogEntry`]s.
+pub fn export_catalog() -> ExecResult<String> {
+    let entries: Vec<_> = EngineeringOptimizationProblem::all()?
+        .iter()
+        .map(ProblemCatalogEntry::from)
+        .collect();
+    serde_json::to_string_pretty(&entries).wrap_err("failed to serialize the problem catalog")
+}
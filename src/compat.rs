@@ -0,0 +1,249 @@
+//! Compatibility layer for the attribute and method names of different enoppy versions.
+
+use std::{
+    ops::Range,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use eyre::eyre;
+use log::warn;
+use mahf::ExecResult;
+use pyo3::{
+    exceptions::{PyAttributeError, PyTypeError, PyValueError},
+    types::PyDict,
+    PyAny, PyErr, PyResult, Python,
+};
+
+const DIMENSION_ATTRS: &[&str] = &["n_dims", "n_vars", "dim"];
+const BOUNDS_ATTRS: &[&str] = &["bounds", "x_bounds"];
+const EVALUATE_METHODS: &[&str] = &["evaluate", "get_objs"];
+const OBJECTIVE_METHODS: &[&str] = &["get_objs", "get_obj"];
+const CONSTRAINT_METHODS: &[&str] = &["get_cons", "get_constraints"];
+
+/// Converts `[lower, upper]` pairs read from `source` into a domain, checking that there is
+/// one pair per dimension and that no lower bound exceeds its upper bound.
+pub(crate) fn validate_domain(
+    source: &str,
+    bounds: Vec<Vec<f64>>,
+    dim: usize,
+) -> Result<Vec<Range<f64>>, String> {
+    if bounds.len() != dim {
+        return Err(format!(
+            "`{source}` has {} entries, but the dimension is {dim}",
+            bounds.len()
+        ));
+    }
+
+    bounds
+        .into_iter()
+        .enumerate()
+        .map(|(i, bound)| match bound[..] {
+            [lower, upper] if lower <= upper => Ok(lower..upper),
+            _ => Err(format!(
+                "`{source}[{i}]` is not a valid `[lower, upper]` pair: {bound:?}"
+            )),
+        })
+        .collect()
+}
+
+/// The attribute and method names provided by an enoppy problem object,
+/// resolved once at construction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct PyApi {
+    dimension: &'static str,
+    bounds: &'static str,
+    evaluate: &'static str,
+    objective: Option<&'static str>,
+    constraints: Option<&'static str>,
+}
+
+impl PyApi {
+    /// Probes which of the known names `problem` provides.
+    ///
+    /// Fails with an error listing the expected and found attributes if a required
+    /// attribute or method is missing.
+    pub fn resolve(problem: &PyAny) -> PyResult<Self> {
+        Ok(Self {
+            dimension: require(problem, DIMENSION_ATTRS, "dimension")?,
+            bounds: require(problem, BOUNDS_ATTRS, "bounds")?,
+            evaluate: require(problem, EVALUATE_METHODS, "evaluation")?,
+            objective: find(problem, OBJECTIVE_METHODS),
+            constraints: find(problem, CONSTRAINT_METHODS),
+        })
+    }
+
+    /// The first of the known names, for problems constructed without probing in tests.
+    #[cfg(test)]
+    pub fn first_known() -> Self {
+        Self {
+            dimension: DIMENSION_ATTRS[0],
+            bounds: BOUNDS_ATTRS[0],
+            evaluate: EVALUATE_METHODS[0],
+            objective: Some(OBJECTIVE_METHODS[0]),
+            constraints: Some(CONSTRAINT_METHODS[0]),
+        }
+    }
+
+    pub fn dimension(&self, problem: &PyAny) -> PyResult<usize> {
+        problem.getattr(self.dimension)?.extract()
+    }
+
+    /// Reads the bounds, validating that there is an ordered `[lower, upper]` pair
+    /// for each of the `dim` dimensions.
+    ///
+    /// Equal bounds are accepted and fix the variable to a constant.
+    pub fn domain(&self, problem: &PyAny, dim: usize) -> PyResult<Vec<Range<f64>>> {
+        let bounds = problem.getattr(self.bounds)?.extract::<Vec<Vec<f64>>>()?;
+        validate_domain(self.bounds, bounds, dim).map_err(PyValueError::new_err)
+    }
+
+    /// Calls the evaluation method, which returns the (possibly penalized) fitness.
+    pub fn fitness<'py>(&self, problem: &'py PyAny, solution: &PyAny) -> PyResult<&'py PyAny> {
+        problem.call_method1(self.evaluate, (solution,))
+    }
+
+    /// Returns the bound evaluation method, e.g. for passing it to a worker pool.
+    pub fn fitness_method<'py>(&self, problem: &'py PyAny) -> PyResult<&'py PyAny> {
+        problem.getattr(self.evaluate)
+    }
+
+    /// Calls the objective method, falling back to the evaluation method if there is none.
+    pub fn objective(&self, problem: &PyAny, solution: &PyAny) -> PyResult<f64> {
+        let method = self.objective.unwrap_or(self.evaluate);
+        self.objectives(problem, solution)?
+            .first()
+            .copied()
+            .ok_or_else(|| PyValueError::new_err(format!("`{method}` returned no objective value")))
+    }
+
+    /// Calls the objective method like [`Self::objective`], returning all objective values.
+    pub fn objectives(&self, problem: &PyAny, solution: &PyAny) -> PyResult<Vec<f64>> {
+        let method = self.objective.unwrap_or(self.evaluate);
+        extract_values(problem.call_method1(method, (solution,))?)
+    }
+
+    /// Calls the constraint method, returning no constraints if there is none.
+    pub fn constraints(&self, problem: &PyAny, solution: &PyAny) -> PyResult<Vec<f64>> {
+        match self.constraints {
+            Some(method) => extract_values(problem.call_method1(method, (solution,))?),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+fn find(problem: &PyAny, names: &[&'static str]) -> Option<&'static str> {
+    names
+        .iter()
+        .copied()
+        .find(|name| problem.hasattr(*name).unwrap_or(false))
+}
+
+fn require(problem: &PyAny, names: &[&'static str], what: &str) -> PyResult<&'static str> {
+    find(problem, names).ok_or_else(|| {
+        let found: Vec<String> = problem
+            .dir()
+            .iter()
+            .filter_map(|attr| attr.extract::<String>().ok())
+            .filter(|attr| !attr.starts_with('_'))
+            .collect();
+        PyAttributeError::new_err(format!(
+            "unsupported enoppy version: expected one of {names:?} for the {what}, found {found:?}"
+        ))
+    })
+}
+
+/// Extracts a number from a Python `float` or `int`, e.g. for problems with integral
+/// objective values.
+pub(crate) fn extract_number(value: &PyAny) -> PyResult<f64> {
+    value.extract::<f64>().or_else(|err| {
+        value
+            .extract::<i64>()
+            .map(|value| value as f64)
+            .map_err(|_| err)
+    })
+}
+
+/// Extracts a (possibly empty) vector of values from a Python scalar, sequence, or `None`.
+///
+/// Values are always extracted as `f64`, but a warning is logged once if the values are
+/// NumPy values of a lower precision, e.g. `float32`.
+pub(crate) fn extract_values(values: &PyAny) -> PyResult<Vec<f64>> {
+    warn_if_reduced_precision(values);
+    if values.is_none() {
+        Ok(Vec::new())
+    } else if let Ok(value) = extract_number(values) {
+        Ok(vec![value])
+    } else if let Ok(values) = values.extract::<Vec<f64>>() {
+        Ok(values)
+    } else {
+        values.iter()?.map(|value| extract_number(value?)).collect()
+    }
+}
+
+/// Extracts the fitness returned by the evaluation method.
+///
+/// Besides a number, which may be an `int`, this accepts an `(objective, penalty)` tuple and a dict with either
+/// a `"fitness"` key or an `"objective"` and optional `"penalty"` key, as returned by some
+/// enoppy versions, where the fitness is the sum of objective and penalty.
+pub(crate) fn extract_fitness(value: &PyAny) -> PyResult<f64> {
+    warn_if_reduced_precision(value);
+    if let Ok(fitness) = extract_number(value) {
+        return Ok(fitness);
+    }
+    if let Ok((objective, penalty)) = value.extract::<(&PyAny, &PyAny)>() {
+        return Ok(extract_number(objective)? + extract_number(penalty)?);
+    }
+    if let Ok(dict) = value.downcast::<PyDict>() {
+        if let Some(fitness) = dict.get_item("fitness") {
+            return extract_number(fitness);
+        }
+        if let Some(objective) = dict.get_item("objective") {
+            let penalty = match dict.get_item("penalty") {
+                Some(penalty) => extract_number(penalty)?,
+                None => 0.0,
+            };
+            return Ok(extract_number(objective)? + penalty);
+        }
+    }
+    Err(PyTypeError::new_err(format!(
+        "expected a number, an `(objective, penalty)` tuple, or a dict as fitness, found `{}`",
+        value.get_type().name()?
+    )))
+}
+
+fn warn_if_reduced_precision(values: &PyAny) {
+    static WARNED: AtomicBool = AtomicBool::new(false);
+
+    let Some(dtype) = values.getattr("dtype").ok() else {
+        return;
+    };
+    let kind = dtype
+        .getattr("kind")
+        .and_then(|kind| kind.extract::<String>());
+    let itemsize = dtype
+        .getattr("itemsize")
+        .and_then(|size| size.extract::<usize>());
+    if let (Ok(kind), Ok(itemsize)) = (kind, itemsize) {
+        if kind == "f" && itemsize < 8 && !WARNED.swap(true, Ordering::Relaxed) {
+            warn!(
+                "enoppy returned values of dtype `{dtype}`, which are less precise than `float64`"
+            );
+        }
+    }
+}
+
+/// Runs `f` with the GIL, converting a raised exception into an error that includes
+/// the Python traceback.
+pub(crate) fn with_traceback<T>(f: impl FnOnce(Python) -> PyResult<T>) -> ExecResult<T> {
+    Python::with_gil(|py| f(py).map_err(|err| py_error(py, err)))
+}
+
+fn py_error(py: Python, err: PyErr) -> eyre::Report {
+    match err
+        .traceback(py)
+        .and_then(|traceback| traceback.format().ok())
+    {
+        Some(traceback) => eyre!("{err}\n{}", traceback.trim_end()),
+        None => eyre!("{err}"),
+    }
+}
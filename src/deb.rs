@@ -0,0 +1,123 @@
+//! Deb's feasibility rules for comparing constrained solutions.
+//!
+//! mahf orders individuals by their [`SingleObjective`] only, so constraint handling
+//! otherwise has to go through the penalty method of
+//! [`EngineeringOptimizationEvaluator::with_penalty`](crate::EngineeringOptimizationEvaluator::with_penalty).
+//!
+//! To use Deb's rules instead, enable
+//! [`EngineeringOptimizationEvaluator::with_constraint_violation`](crate::EngineeringOptimizationEvaluator::with_constraint_violation)
+//! and, in a custom selection or replacement component, read the [`ConstraintViolations`]
+//! from the state after evaluation and order the population with
+//! [`ConstraintViolations::constrained_objectives`].
+
+use std::cmp::Ordering;
+
+use mahf::{Individual, SingleObjective, SingleObjectiveProblem};
+use pyo3::Python;
+
+use crate::{ConstraintViolation, ConstraintViolations, EngineeringOptimizationEvaluator};
+
+/// Compares two solutions by Deb's rules, where [`Ordering::Less`] means `a` is better.
+///
+/// A feasible solution beats an infeasible one, two feasible solutions are compared by
+/// objective, and two infeasible solutions are compared by total violation.
+pub fn deb_compare(
+    a: (SingleObjective, ConstraintViolation),
+    b: (SingleObjective, ConstraintViolation),
+) -> Ordering {
+    let ((a_objective, a_violation), (b_objective, b_violation)) = (a, b);
+    match (a_violation.is_feasible(), b_violation.is_feasible()) {
+        (true, true) => a_objective.cmp(&b_objective),
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => a_violation.0.total_cmp(&b_violation.0),
+    }
+}
+
+/// An objective value paired with its [`ConstraintViolation`], ordered by [`deb_compare`].
+#[derive(Clone, Copy, Debug)]
+pub struct ConstrainedObjective {
+    pub objective: SingleObjective,
+    pub violation: ConstraintViolation,
+}
+
+impl PartialEq for ConstrainedObjective {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for ConstrainedObjective {}
+
+impl PartialOrd for ConstrainedObjective {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ConstrainedObjective {
+    fn cmp(&self, other: &Self) -> Ordering {
+        deb_compare(
+            (self.objective, self.violation),
+            (other.objective, other.violation),
+        )
+    }
+}
+
+impl ConstraintViolations {
+    /// Pairs the objectives of `individuals` with the recorded violations.
+    ///
+    /// `individuals` must be the batch that was evaluated last, in the same order.
+    pub fn constrained_objectives<P: SingleObjectiveProblem>(
+        &self,
+        individuals: &[Individual<P>],
+    ) -> Vec<ConstrainedObjective> {
+        individuals
+            .iter()
+            .zip(&self.0)
+            .map(|(individual, &violation)| ConstrainedObjective {
+                objective: *individual.objective(),
+                violation,
+            })
+            .collect()
+    }
+
+    /// Returns the index of the best individual according to Deb's rules.
+    pub fn best_index<P: SingleObjectiveProblem>(
+        &self,
+        individuals: &[Individual<P>],
+    ) -> Option<usize> {
+        self.constrained_objectives(individuals)
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, objective)| **objective)
+            .map(|(i, _)| i)
+    }
+}
+
+impl EngineeringOptimizationEvaluator {
+    /// Returns the indices of `solutions` ordered from best to worst according to
+    /// [`deb_compare`], keeping the order of equally good solutions.
+    ///
+    /// Objectives and constraints are evaluated with
+    /// [`Self::evaluate_feasibility`], all while holding the GIL once.
+    pub fn rank(&self, solutions: &[Vec<f64>]) -> Vec<usize> {
+        let objectives: Vec<_> = Python::with_gil(|_| {
+            solutions
+                .iter()
+                .map(|solution| {
+                    let feasibility = self.evaluate_feasibility(solution);
+                    ConstrainedObjective {
+                        objective: SingleObjective::try_from(feasibility.objective)
+                            .unwrap_or_default(),
+                        violation: ConstraintViolation(feasibility.total_violation),
+                    }
+                })
+                .collect()
+        });
+
+        let mut indices: Vec<_> = (0..solutions.len()).collect();
+        indices.sort_by_key(|&i| objectives[i]);
+        indices
+    }
+}
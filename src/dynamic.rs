@@ -0,0 +1,62 @@
+//! Object-safe access to problems, e.g. for runtime registries.
+
+use std::ops::Range;
+
+use mahf::Problem;
+
+use crate::{EngineeringOptimizationEvaluator, EngineeringOptimizationProblem};
+
+/// An object-safe façade over a problem, which allows storing heterogeneous
+/// problems as `Box<dyn DynEngineeringProblem>`.
+///
+/// Besides [`EngineeringOptimizationProblem`], it is implemented by every evaluator of a
+/// single problem with real-valued solutions, so configured evaluators can be stored
+/// side by side as well.
+pub trait DynEngineeringProblem: Send + Sync {
+    fn name(&self) -> &str;
+
+    fn dimension(&self) -> usize;
+
+    fn domain(&self) -> Vec<Range<f64>>;
+
+    /// Evaluates the objective value of `solution`, see
+    /// [`EngineeringOptimizationEvaluator::evaluate_one`].
+    fn evaluate_one(&self, solution: &[f64]) -> f64;
+}
+
+impl DynEngineeringProblem for EngineeringOptimizationProblem {
+    fn name(&self) -> &str {
+        Problem::name(self)
+    }
+
+    fn dimension(&self) -> usize {
+        self.dim
+    }
+
+    fn domain(&self) -> Vec<Range<f64>> {
+        self.domain_ref().to_vec()
+    }
+
+    /// Evaluates `solution` with a temporary evaluator using the default configuration.
+    fn evaluate_one(&self, solution: &[f64]) -> f64 {
+        self.evaluator().evaluate_one(solution)
+    }
+}
+
+impl DynEngineeringProblem for EngineeringOptimizationEvaluator {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn dimension(&self) -> usize {
+        self.domain.len()
+    }
+
+    fn domain(&self) -> Vec<Range<f64>> {
+        self.domain.to_vec()
+    }
+
+    fn evaluate_one(&self, solution: &[f64]) -> f64 {
+        EngineeringOptimizationEvaluator::evaluate_one(self, solution)
+    }
+}
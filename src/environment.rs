@@ -0,0 +1,107 @@
+//! Diagnostics for the Python environment.
+
+use std::path::Path;
+
+use eyre::{eyre, WrapErr};
+use log::warn;
+use mahf::ExecResult;
+use pyo3::{PyResult, Python};
+
+/// Information about the active Python environment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnvInfo {
+    /// The path of the Python interpreter (`sys.executable`).
+    pub python_executable: String,
+    /// The version of the Python interpreter.
+    pub python_version: String,
+    /// The version of the installed NumPy package.
+    pub numpy_version: String,
+    /// The `__version__` of the installed enoppy package, if it provides one.
+    pub enoppy_version: Option<String>,
+}
+
+/// Checks that NumPy and enoppy can be imported and returns information about the environment.
+///
+/// Applications can call this at startup to report a missing or incompatible
+/// installation before constructing any problem.
+/// Evaluation passes solutions as NumPy arrays, so a missing NumPy would otherwise only
+/// surface as a panic at the first evaluation.
+pub fn check_environment() -> ExecResult<EnvInfo> {
+    let (python_executable, python_version) = Python::with_gil(|py| -> PyResult<_> {
+        let sys = py.import("sys")?;
+        let executable = sys.getattr("executable")?.extract::<String>()?;
+        Ok((executable, py.version().to_string()))
+    })
+    .wrap_err("failed to inspect the Python interpreter")?;
+
+    let numpy_version = Python::with_gil(|py| -> PyResult<_> {
+        py.import("numpy")?
+            .getattr("__version__")?
+            .extract::<String>()
+    })
+    .map_err(|err| {
+        eyre!(
+            "failed to import NumPy with the Python interpreter at `{python_executable}`: {err}\n\
+            Please install it using `pip install \"numpy<2\"`"
+        )
+    })?;
+    if !numpy_version.starts_with("1.") {
+        warn!(
+            "NumPy {numpy_version} is installed, but only NumPy 1.x is supported, \
+            evaluations may fail or crash"
+        );
+    }
+
+    let enoppy_version = Python::with_gil(|py| -> PyResult<_> {
+        let enoppy = py.import("enoppy")?;
+        let version = enoppy
+            .getattr("__version__")
+            .and_then(|version| version.extract::<String>())
+            .ok();
+        Ok(version)
+    })
+    .map_err(|err| {
+        eyre!(
+            "failed to import enoppy with the Python interpreter at `{python_executable}`: {err}\n\
+            Please install it using `pip install enoppy`"
+        )
+    })?;
+
+    Ok(EnvInfo {
+        python_executable,
+        python_version,
+        numpy_version,
+        enoppy_version,
+    })
+}
+
+/// Makes the packages of the virtual environment at `venv_path` importable by inserting
+/// its `site-packages` directory at the front of `sys.path`.
+///
+/// Call this before loading any problem, as modules that were already imported are not
+/// reloaded.
+pub fn configure_python(venv_path: &Path) -> ExecResult<()> {
+    Python::with_gil(|py| {
+        let (major, minor) = (py.version_info().major, py.version_info().minor);
+        let candidates = [
+            venv_path
+                .join("lib")
+                .join(format!("python{major}.{minor}"))
+                .join("site-packages"),
+            venv_path.join("Lib").join("site-packages"),
+        ];
+        let site_packages = candidates
+            .into_iter()
+            .find(|path| path.is_dir())
+            .ok_or_else(|| {
+                eyre!(
+                    "found no `site-packages` for Python {major}.{minor} in `{}`",
+                    venv_path.display()
+                )
+            })?;
+
+        let path = py.import("sys")?.getattr("path")?;
+        path.call_method1("insert", (0, site_packages.to_string_lossy().as_ref()))?;
+        Ok(())
+    })
+}
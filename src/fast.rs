@@ -0,0 +1,101 @@
+//! Minimal-overhead evaluation of the penalized objective value.
+
+use std::{ops::Range, sync::Arc};
+
+use mahf::{problems::Evaluate, ExecResult, Individual, SingleObjective, State};
+use numpy::PyArray1;
+use pyo3::{PyObject, Python, ToPyObject};
+
+use crate::{
+    compat::{extract_fitness, with_traceback},
+    DynEngineeringProblem, EngineeringOptimizationProblem,
+};
+
+/// A lean evaluator calling the evaluation method of enoppy, which returns the
+/// penalized objective value, once per solution and nothing else.
+///
+/// The bound method is looked up once at construction, and none of the optional features
+/// of [`EngineeringOptimizationEvaluator`](crate::EngineeringOptimizationEvaluator) are
+/// available, i.e. no clamping, constraint handling, statistics, callbacks, or timeouts.
+/// Errors raised in Python and NaN results are assigned [`f64::INFINITY`] without a
+/// warning.
+///
+/// Objective values are negated for maximization problems.
+#[derive(Clone)]
+pub struct FastEvaluator {
+    method: PyObject,
+    minimize: bool,
+    name: String,
+    domain: Arc<[Range<f64>]>,
+}
+
+impl FastEvaluator {
+    pub fn new(problem: &EngineeringOptimizationProblem) -> ExecResult<Self> {
+        let method = with_traceback(|py| {
+            let method = problem.api.fitness_method(problem.inner.as_ref(py))?;
+            Ok(method.to_object(py))
+        })?;
+        Ok(Self {
+            method,
+            minimize: problem.minimize,
+            name: problem.name.clone(),
+            domain: problem.domain.clone(),
+        })
+    }
+
+    /// Evaluates the penalized objective value of `solution`.
+    pub fn evaluate_one(&self, solution: &[f64]) -> f64 {
+        Python::with_gil(|py| self.evaluate_with_gil(py, solution))
+    }
+
+    fn evaluate_with_gil(&self, py: Python, solution: &[f64]) -> f64 {
+        let fitness = self
+            .method
+            .as_ref(py)
+            .call1((PyArray1::from_slice(py, solution),))
+            .and_then(extract_fitness)
+            .unwrap_or(f64::INFINITY);
+        match fitness {
+            fitness if fitness.is_nan() => f64::INFINITY,
+            fitness if self.minimize => fitness,
+            fitness => -fitness,
+        }
+    }
+}
+
+impl Evaluate for FastEvaluator {
+    type Problem = EngineeringOptimizationProblem;
+
+    fn evaluate(
+        &mut self,
+        _problem: &Self::Problem,
+        _state: &mut State<Self::Problem>,
+        individuals: &mut [Individual<Self::Problem>],
+    ) {
+        Python::with_gil(|py| {
+            for individual in individuals.iter_mut() {
+                let objective = self.evaluate_with_gil(py, individual.solution());
+                individual.set_objective(SingleObjective::try_from(objective).unwrap_or_default());
+            }
+        });
+    }
+}
+
+impl DynEngineeringProblem for FastEvaluator {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn dimension(&self) -> usize {
+        self.domain.len()
+    }
+
+    fn domain(&self) -> Vec<Range<f64>> {
+        self.domain.to_vec()
+    }
+
+    /// Evaluates the penalized objective value of `solution`, see [`Self::evaluate_one`].
+    fn evaluate_one(&self, solution: &[f64]) -> f64 {
+        FastEvaluator::evaluate_one(self, solution)
+    }
+}
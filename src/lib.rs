@@ -1,128 +1,1186 @@
-use std::ops::Range;
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    convert::Infallible,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Range,
+    sync::{
+        mpsc::{self, Receiver},
+        Arc, Mutex, MutexGuard, OnceLock, PoisonError,
+    },
+    thread,
+    time::{Duration, Instant},
+};
 
+use better_any::{Tid, TidAble};
+use eyre::{eyre, WrapErr};
+use log::warn;
 use mahf::{
-    problems::{Evaluate, LimitedVectorProblem, VectorProblem},
-    ExecResult, Individual, Problem, SingleObjective, State,
+    problems::{Evaluate, KnownOptimumProblem, LimitedVectorProblem, VectorProblem},
+    CustomState, ExecResult, Individual, Problem, SingleObjective, State,
+};
+use numpy::{
+    ndarray::{Array1, Array2, ArrayView1, ArrayView2},
+    IntoPyArray, PyArray1, PyArray2,
+};
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    types::{IntoPyDict, PyType},
+    IntoPy, PyAny, PyErr, PyObject, PyResult, Python,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+    best::BestSoFar,
+    compat::{extract_fitness, extract_values, validate_domain, with_traceback, PyApi},
+    timeout::with_timeout,
 };
-use numpy::{ndarray::Array1, IntoPyArray};
-use pyo3::{IntoPy, PyObject, Python};
 
+mod aggregated;
+#[cfg(feature = "argmin")]
+mod argmin;
+mod best;
+mod buffered;
+mod builder;
+mod caching;
+#[cfg(feature = "serde")]
+mod catalog;
+mod compat;
+mod deb;
+mod dynamic;
+mod environment;
+mod fast;
+mod metadata;
+mod multi;
+#[cfg(feature = "parallel")]
+mod parallel;
+#[cfg(feature = "serde")]
+mod record;
+mod repair;
+mod rwco;
+mod stats;
+mod suite;
+mod table;
+mod timeout;
+mod unit;
+
+pub use aggregated::{DebAggregatedEvaluator, DebAggregatedProblem};
+pub use best::BestSolution;
+pub use buffered::BufferedEvaluator;
+pub use builder::EngineeringOptimizationProblemBuilder;
+pub use caching::CachingEvaluator;
+#[cfg(feature = "serde")]
+pub use catalog::{export_catalog, ProblemCatalogEntry};
+pub use deb::{deb_compare, ConstrainedObjective};
+pub use dynamic::DynEngineeringProblem;
+pub use environment::{check_environment, configure_python, EnvInfo};
+pub use fast::FastEvaluator;
+pub use multi::{MultiObjectiveEngineeringEvaluator, MultiObjectiveEngineeringProblem};
+#[cfg(feature = "parallel")]
+pub use parallel::ParallelEvaluator;
+#[cfg(feature = "serde")]
+pub use record::{append_records_to_csv, Record};
+pub use repair::{RepairStrategy, Repaired};
+pub use rwco::RwcoProblem;
+pub use stats::EvaluationStats;
+pub use suite::{par_experiment_rwco, rwco_suite, SuiteEvaluator};
+pub use table::{Divergence, TableEvaluator};
+pub use unit::UnitCubeEvaluator;
+
+const RWCO_2020: &str = "enoppy.paper_based.rwco_2020";
+
+/// The tolerance for equality constraints used if the problem doesn't define one,
+/// as in the CEC 2020 competition on real-world constrained optimization.
+pub const DEFAULT_EQUALITY_TOLERANCE: f64 = 1e-4;
+
+/// Returns the sorted names of all problem classes available in the RWCO 2020 module of enoppy.
+///
+/// Only classes defined in that module are listed, so imported base classes are skipped.
+pub fn available_problems() -> ExecResult<Vec<String>> {
+    Python::with_gil(|py| -> PyResult<_> {
+        let problems = Python::import(py, RWCO_2020)?;
+        let mut names = Vec::new();
+        for name in problems.dir() {
+            let name = name.extract::<String>()?;
+            if !name.ends_with("Problem") {
+                continue;
+            }
+            let class = problems.getattr(name.as_str())?;
+            if class.is_instance_of::<PyType>()
+                && class.getattr("__module__")?.extract::<&str>()? == RWCO_2020
+            {
+                names.push(name);
+            }
+        }
+        names.sort();
+        Ok(names)
+    })
+    .wrap_err("failed to list available problems")
+}
+
+/// Lists the available problems of `category`, see [`available_problems`].
+pub fn available_problems_in_category(category: ProblemCategory) -> ExecResult<Vec<String>> {
+    let mut problems = available_problems()?;
+    problems.retain(|name| metadata::category(name) == Some(category));
+    Ok(problems)
+}
+
+/// The application domain of a problem.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProblemCategory {
+    /// Industrial chemical processes, e.g. heat exchanger networks and pooling.
+    ChemicalProcess,
+    /// Process synthesis and design problems.
+    ProcessSynthesis,
+    /// Mechanical design problems, e.g. the speed reducer and pressure vessel.
+    MechanicalDesign,
+    /// Structural design problems, i.e. the welded beam and three-bar truss, which the
+    /// suite counts as mechanical design problems.
+    StructuralDesign,
+    /// Power system problems, which enoppy does not provide yet.
+    PowerSystem,
+}
+
+/// The type of a decision variable.
+#[derive(Clone, Debug, PartialEq)]
+pub enum VarType {
+    /// A real-valued variable.
+    Continuous,
+    /// An integer-valued variable.
+    Integer,
+    /// A variable restricted to a set of values.
+    Discrete(Vec<f64>),
+}
+
+impl VarType {
+    /// Maps `x` to the closest value allowed for this variable type.
+    pub fn round(&self, x: f64) -> f64 {
+        match self {
+            VarType::Continuous => x,
+            VarType::Integer => x.round(),
+            VarType::Discrete(values) => values
+                .iter()
+                .copied()
+                .min_by(|a, b| (a - x).abs().total_cmp(&(b - x).abs()))
+                .unwrap_or(x),
+        }
+    }
+}
+
+/// How evaluators treat objective values that aren't valid [`SingleObjective`]s,
+/// i.e. NaN and negative infinity.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NanPolicy {
+    /// Replaces invalid values with [`f64::INFINITY`].
+    #[default]
+    ToInfinity,
+    /// Keeps invalid values in raw outputs like
+    /// [`EngineeringOptimizationEvaluator::evaluate_one`].
+    ///
+    /// Since mahf objectives can't represent them, individuals still receive
+    /// [`f64::INFINITY`].
+    Keep,
+    /// Treats invalid values as errors.
+    ///
+    /// [`EngineeringOptimizationEvaluator::try_evaluate`] returns them, while other
+    /// evaluations log a warning and assign [`f64::INFINITY`] like for errors raised in
+    /// Python.
+    Error,
+}
+
+impl NanPolicy {
+    fn apply(self, value: f64, problem: &str) -> PyResult<f64> {
+        if !value.is_nan() && value != f64::NEG_INFINITY {
+            return Ok(value);
+        }
+        match self {
+            NanPolicy::ToInfinity => Ok(f64::INFINITY),
+            NanPolicy::Keep => Ok(value),
+            NanPolicy::Error => Err(PyValueError::new_err(format!(
+                "evaluation of `{problem}` returned {value}"
+            ))),
+        }
+    }
+}
+
+/// How batches of solutions are arranged in the NumPy array passed to vectorized Python
+/// calls, see [`EngineeringOptimizationEvaluator::with_batch_layout`].
+///
+/// Problems that iterate over the solutions of a batch expect
+/// [`BatchLayout::RowMajorSolutions`], while problems that vectorize by broadcasting their
+/// formulas over the variables `x[0]`, `x[1]`, ... expect
+/// [`BatchLayout::ColumnMajorSolutions`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BatchLayout {
+    /// An array of shape `(count, dimension)` with one solution per row.
+    #[default]
+    RowMajorSolutions,
+    /// An array of shape `(dimension, count)` with one solution per column.
+    ColumnMajorSolutions,
+}
+
+/// A serializable description of an [`EngineeringOptimizationProblem`].
+///
+/// Serialization support requires the `serde` feature.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProblemSpec {
+    pub name: String,
+    pub module: String,
+    pub dim: usize,
+    pub domain: Vec<Range<f64>>,
+}
+
+/// An enoppy problem, wrapping a single Python problem instance.
+///
+/// Metadata is read from that instance once when loading, and every evaluator created
+/// with [`EngineeringOptimizationProblem::evaluator`] shares the same instance, so problems
+/// with internal state observe all evaluations.
+/// Clones share the Python instance as well, so cloning doesn't call into Python.
+///
+/// # Metadata
+///
+/// All metadata is materialized when loading, so the accessors, e.g. [`Problem::name`],
+/// [`VectorProblem::dimension`], [`LimitedVectorProblem::domain`], the bounds, and the
+/// constraint counts, never acquire the GIL.
+/// They are therefore cheap in tight loops and can be called from threads that never
+/// interact with Python.
+/// Only loading, [`Self::with_seed`], [`Self::close`], [`Self::suggested_penalty`], the
+/// first call of [`Self::objective_scale`], [`Self::evaluator`], and the methods taking an
+/// evaluator call into Python.
+///
+/// # Lifecycle
+///
+/// The Python instance is reference counted and freed once the problem, all its clones,
+/// and all its evaluators are dropped.
+/// Dropping a handle without holding the GIL only queues the decrement until the next time
+/// any thread acquires the GIL, so call [`Self::close`] to release the reference promptly,
+/// e.g. in long-running services that create short-lived problems.
+#[derive(Clone, Debug)]
 pub struct EngineeringOptimizationProblem {
+    // Metadata read by `load`, accessors must not call into Python.
     name: String,
+    module: String,
     dim: usize,
-    domain: Vec<Range<f64>>,
+    domain: Arc<[Range<f64>]>,
+    global_optimum: Option<f64>,
+    optimal_solution: Option<Vec<f64>>,
+    baseline: Option<Vec<f64>>,
+    variable_types: Vec<VarType>,
+    variable_names: Option<Vec<String>>,
+    num_inequality: usize,
+    num_equality: usize,
+    equality_tolerance: f64,
+    minimize: bool,
+    // Estimated on first use and shared between clones.
+    objective_scale: Arc<OnceLock<f64>>,
+    // The Python instance, only used by methods that need to call into Python.
+    api: PyApi,
+    inner: PyObject,
 }
 
 impl EngineeringOptimizationProblem {
     pub fn new(name: impl AsRef<str>) -> ExecResult<Self> {
-        Python::with_gil(|py| {
-            let problems = Python::import(py, "enoppy.paper_based.rwco_2020")?;
-            let py_problem_class = problems.getattr(name.as_ref())?;
-            let py_problem = py_problem_class.call0()?;
-            let dim = py_problem.getattr("n_dims")?.extract::<usize>()?;
-            let bounds = py_problem.getattr("bounds")?.extract::<Vec<Vec<f64>>>()?;
-            let domain = bounds.into_iter().map(|bound| bound[0]..bound[1]).collect();
+        Self::from_module(RWCO_2020, name.as_ref())
+    }
+
+    /// Loads the RWCO 2020 problem at the zero-based index `i` of [`available_problems`].
+    ///
+    /// Indices follow the alphabetical order of the class names, not the numbering of the
+    /// paper, so they are stable as long as the installed enoppy version provides the same
+    /// problem classes, and [`Self::index_of`] is the inverse mapping.
+    pub fn by_index(i: usize) -> ExecResult<Self> {
+        let problems = available_problems()?;
+        let name = problems
+            .get(i)
+            .ok_or_else(|| eyre!("no problem at index {i}, there are {}", problems.len()))?;
+        Self::new(name)
+    }
+
+    /// Returns the index of the RWCO 2020 problem `name`, see [`Self::by_index`].
+    pub fn index_of(name: &str) -> ExecResult<Option<usize>> {
+        Ok(available_problems()?
+            .iter()
+            .position(|problem| problem == name))
+    }
+
+    /// Loads the problem `class` from an arbitrary enoppy `module`, e.g.
+    /// `from_module("enoppy.paper_based.pdo_2022", "WeldedBeamProblem")`.
+    pub fn from_module(module: &str, class: &str) -> ExecResult<Self> {
+        Self::load(module, class, None, |class| class.call0())
+    }
+
+    /// Loads the RWCO 2020 problem `name` with dimension `dim`, for problems that
+    /// support scaling their dimension.
+    ///
+    /// The dimension is passed as `n_dims` keyword argument to the constructor.
+    /// If the problem does not accept it, a warning is logged and the problem
+    /// is loaded with its default dimension.
+    pub fn new_with_dim(name: &str, dim: usize) -> ExecResult<Self> {
+        Self::load_with_dim(RWCO_2020, name, dim)
+    }
+
+    /// Loads the RWCO 2020 problem `name`, passing `kwargs` as keyword arguments to the
+    /// constructor, e.g. to configure a noise level or variant.
+    ///
+    /// All values are passed as Python floats, so use [`Self::new_with_dim`] to set the
+    /// dimension.
+    /// The arguments are not part of the [`ProblemSpec`], i.e. [`Self::from_spec`]
+    /// constructs the problem with its default configuration.
+    pub fn new_with_kwargs(name: &str, kwargs: HashMap<String, f64>) -> ExecResult<Self> {
+        Self::load(RWCO_2020, name, None, |class| {
+            class.call((), Some(kwargs.into_py_dict(class.py())))
+        })
+    }
+
+    /// Loads the problem `name` from `module`, passing `dim` as `n_dims`, see
+    /// [`Self::new_with_dim`].
+    fn load_with_dim(module: &str, name: &str, dim: usize) -> ExecResult<Self> {
+        Self::load(module, name, None, |class| {
+            let py = class.py();
+            let kwargs = [("n_dims", dim)].into_py_dict(py);
+            class.call((), Some(kwargs)).or_else(|err| {
+                if err.is_instance_of::<PyTypeError>(py) {
+                    warn!("`{name}` does not support setting the dimension, using the default");
+                    class.call0()
+                } else {
+                    Err(err)
+                }
+            })
+        })
+    }
+
+    /// Reconstructs the problem described by `spec`.
+    ///
+    /// The dimension and domain are taken from the spec instead of being read from Python.
+    /// If the default dimension of the problem differs from the spec, e.g. for problems
+    /// loaded with [`Self::new_with_dim`], the dimension is passed to the constructor like
+    /// there, and an error is returned if the problem does not support it.
+    /// Like bounds read from Python, the domain must have one range per dimension, none of
+    /// which has a lower bound above its upper bound.
+    pub fn from_spec(spec: ProblemSpec) -> ExecResult<Self> {
+        let dim = spec.dim;
+        let bounds = spec
+            .domain
+            .iter()
+            .map(|range| vec![range.start, range.end])
+            .collect();
+        let domain = validate_domain("domain", bounds, dim)
+            .map_err(|err| eyre!("invalid spec of problem `{}`: {err}", spec.name))?;
+        let cached = Some((dim, domain));
+        Self::load(&spec.module, &spec.name, cached, |class| {
+            let py_problem = class.call0()?;
+            if PyApi::resolve(py_problem)?.dimension(py_problem)? == dim {
+                return Ok(py_problem);
+            }
+
+            let kwargs = [("n_dims", dim)].into_py_dict(class.py());
+            let py_problem = class.call((), Some(kwargs))?;
+            let actual = PyApi::resolve(py_problem)?.dimension(py_problem)?;
+            if actual != dim {
+                return Err(PyValueError::new_err(format!(
+                    "the spec has dimension {dim}, but the problem has dimension {actual}"
+                )));
+            }
+            Ok(py_problem)
+        })
+    }
+
+    /// Seeds the randomness of the Python problem for reproducible evaluations.
+    ///
+    /// The seed is passed to a `set_seed` method or assigned to a `seed` attribute,
+    /// whichever the problem provides, and ignored otherwise.
+    /// All RWCO 2020 problems are deterministic, so this is a no-op for them.
+    pub fn with_seed(self, seed: u64) -> ExecResult<Self> {
+        with_traceback(|py| {
+            let py_problem = self.inner.as_ref(py);
+            if py_problem.hasattr("set_seed")? {
+                py_problem.call_method1("set_seed", (seed,))?;
+            } else if py_problem.hasattr("seed")? {
+                py_problem.setattr("seed", seed)?;
+            }
+            Ok(())
+        })
+        .wrap_err_with(|| format!("failed to seed problem `{}`", self.name))?;
+        Ok(self)
+    }
+
+    /// Drops this handle while holding the GIL, which releases its reference to the Python
+    /// instance immediately.
+    ///
+    /// The instance itself is only freed if no clone or evaluator still refers to it.
+    pub fn close(self) {
+        Python::with_gil(|_| drop(self));
+    }
+
+    /// Returns the [`ProblemSpec`] describing this problem.
+    pub fn spec(&self) -> ProblemSpec {
+        ProblemSpec {
+            name: self.name.clone(),
+            module: self.module.clone(),
+            dim: self.dim,
+            domain: self.domain.to_vec(),
+        }
+    }
+
+    fn load(
+        module: &str,
+        class: &str,
+        cached: Option<(usize, Vec<Range<f64>>)>,
+        instantiate: impl for<'py> FnOnce(&'py PyAny) -> PyResult<&'py PyAny>,
+    ) -> ExecResult<Self> {
+        // The hardcoded metadata only applies to the RWCO 2020 problems.
+        let rwco_class = (module == RWCO_2020).then_some(class);
+
+        with_traceback(|py| -> PyResult<Self> {
+            let problems = Python::import(py, module)?;
+            let py_problem_class = problems.getattr(class)?;
+            let py_problem = instantiate(py_problem_class)?;
+            let api = PyApi::resolve(py_problem)?;
+            let (dim, domain) = match cached {
+                Some(cached) => cached,
+                None => {
+                    let dim = api.dimension(py_problem)?;
+                    (dim, api.domain(py_problem, dim)?)
+                }
+            };
+            let (py_solution, py_value) = py_global_optimum(py_problem);
+            let global_optimum =
+                py_value.or_else(|| rwco_class.and_then(metadata::global_optimum_value));
+            let optimal_solution =
+                py_solution.or_else(|| rwco_class.and_then(metadata::optimal_solution));
+            let baseline = py_baseline(py_problem, dim);
+            let variable_types = rwco_class
+                .and_then(metadata::variable_types)
+                .filter(|types| types.len() == dim)
+                .unwrap_or_else(|| vec![VarType::Continuous; dim]);
+            let variable_names = py_variable_names(py_problem)
+                .or_else(|| rwco_class.and_then(metadata::variable_names))
+                .filter(|names| names.len() == dim);
+            let num_equality = py_count(py_problem, &["n_eq_cons", "n_eq"]).unwrap_or_default();
+            let num_inequality = py_count(py_problem, &["n_ineq_cons", "n_ineq"])
+                .or_else(|| {
+                    py_count(py_problem, &["n_cons"]).map(|n| n.saturating_sub(num_equality))
+                })
+                .unwrap_or_default();
 
             let problem = Self {
-                name: name.as_ref().to_string(),
+                name: class.to_string(),
+                module: module.to_string(),
                 dim,
-                domain,
+                domain: domain.into(),
+                global_optimum,
+                optimal_solution,
+                baseline,
+                variable_types,
+                variable_names,
+                num_inequality,
+                num_equality,
+                equality_tolerance: py_equality_tolerance(py_problem),
+                minimize: py_is_minimization(py_problem),
+                objective_scale: Arc::default(),
+                api,
+                inner: py_problem.into_py(py),
             };
 
             Ok(problem)
         })
+        .wrap_err_with(|| format!("failed to load problem `{class}` from `{module}`"))
+    }
+
+    /// Loads all problems listed by [`available_problems`].
+    ///
+    /// Problems that fail to load are skipped and a warning is logged, so only
+    /// failing to list the problems is returned as an error.
+    pub fn all() -> ExecResult<Vec<Self>> {
+        let problems = available_problems()?
+            .into_iter()
+            .filter_map(|name| {
+                Self::new(&name)
+                    .map_err(|err| warn!("skipping problem `{name}`: {err:#}"))
+                    .ok()
+            })
+            .collect();
+        Ok(problems)
+    }
+
+    /// Lazily loads the problems listed by [`available_problems`], constructing each
+    /// problem only when the iterator advances.
+    ///
+    /// If listing the problems fails, the iterator yields only that error.
+    pub fn iter_all() -> impl Iterator<Item = ExecResult<Self>> {
+        let (names, error) = match available_problems() {
+            Ok(names) => (names, None),
+            Err(err) => (Vec::new(), Some(err)),
+        };
+        error
+            .map(Err)
+            .into_iter()
+            .chain(names.into_iter().map(Self::new))
+    }
+
+    /// Returns the path of the Python module the problem was loaded from.
+    pub fn module(&self) -> &str {
+        &self.module
+    }
+
+    /// Returns an identifier combining the last component of the [module](Self::module) and
+    /// the class name, e.g. `rwco_2020::WeldedBeamDesignProblem`.
+    ///
+    /// Unlike [`Problem::name`], this distinguishes classes of the same name in different
+    /// modules, e.g. in result tables.
+    pub fn qualified_name(&self) -> String {
+        let module = self.module.rsplit('.').next().unwrap_or(&self.module);
+        format!("{module}::{}", self.name)
+    }
+
+    /// Returns the domain without allocating, unlike [`LimitedVectorProblem::domain`],
+    /// whose signature requires returning a new `Vec`.
+    pub fn domain_ref(&self) -> &[Range<f64>] {
+        &self.domain
+    }
+
+    /// Returns a shared handle to the domain, which is cheap to clone.
+    pub fn shared_domain(&self) -> Arc<[Range<f64>]> {
+        self.domain.clone()
+    }
+
+    /// Returns the lower bound of each dimension.
+    pub fn lower_bounds(&self) -> Vec<f64> {
+        self.domain.iter().map(|range| range.start).collect()
+    }
+
+    /// Returns the upper bound of each dimension.
+    pub fn upper_bounds(&self) -> Vec<f64> {
+        self.domain.iter().map(|range| range.end).collect()
+    }
+
+    /// Returns the center of the domain.
+    pub fn midpoint(&self) -> Vec<f64> {
+        self.domain
+            .iter()
+            .map(|range| (range.start + range.end) / 2.0)
+            .collect()
+    }
+
+    /// Returns the corner of the domain with all variables at their lower bound.
+    pub fn lower_corner(&self) -> Vec<f64> {
+        self.lower_bounds()
+    }
+
+    /// Returns the corner of the domain with all variables at their upper bound.
+    pub fn upper_corner(&self) -> Vec<f64> {
+        self.upper_bounds()
+    }
+
+    /// Maps `x` from the domain into the unit hypercube.
+    ///
+    /// Coordinates of degenerate ranges, where start and end coincide, are mapped to zero.
+    pub fn to_unit(&self, x: &[f64]) -> Vec<f64> {
+        x.iter()
+            .zip(self.domain.iter())
+            .map(|(&x, range)| {
+                let width = range.end - range.start;
+                if width > 0.0 {
+                    (x - range.start) / width
+                } else {
+                    0.0
+                }
+            })
+            .collect()
+    }
+
+    /// Maps `u` from the unit hypercube into the domain, i.e. the inverse of [`Self::to_unit`].
+    ///
+    /// Coordinates of degenerate ranges are mapped to their constant value.
+    pub fn from_unit(&self, u: &[f64]) -> Vec<f64> {
+        from_unit(&self.domain, u)
+    }
+
+    /// Samples a solution uniformly within the domain.
+    pub fn random_solution<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
+        random_in(&self.domain, rng)
+    }
+
+    /// Samples solutions uniformly within the domain until one satisfies all constraints
+    /// according to `evaluator`.
+    ///
+    /// If no feasible solution is found within `max_attempts` samples, the sample with
+    /// the lowest [`ConstraintViolation`] is returned.
+    pub fn random_feasible_solution<R: Rng + ?Sized>(
+        &self,
+        evaluator: &EngineeringOptimizationEvaluator,
+        rng: &mut R,
+        max_attempts: usize,
+    ) -> Vec<f64> {
+        let mut best: Option<(Vec<f64>, ConstraintViolation)> = None;
+        for _ in 0..max_attempts.max(1) {
+            let solution = self.random_solution(rng);
+            let violation = evaluator.constraint_violation(&solution);
+            if violation.is_feasible() {
+                return solution;
+            }
+            if best.as_ref().is_none_or(|(_, best)| violation < *best) {
+                best = Some((solution, violation));
+            }
+        }
+        best.map(|(solution, _)| solution).unwrap()
+    }
+
+    /// Returns the known (or best-known) optimal objective value, if available.
+    ///
+    /// The value is read from enoppy's `get_global_optimum` if provided, and otherwise
+    /// taken from the reference values published alongside the RWCO 2020 suite.
+    pub fn global_optimum_value(&self) -> Option<f64> {
+        self.global_optimum
+    }
+
+    /// Returns the gap between `objective` and the known optimum relative to the optimum,
+    /// or `None` if no optimum is known.
+    ///
+    /// Both values are taken as reported by Python, i.e. not negated for maximization problems.
+    pub fn relative_error(&self, objective: f64) -> Option<f64> {
+        self.global_optimum
+            .map(|optimum| (objective - optimum) / optimum.abs().max(f64::EPSILON))
+    }
+
+    /// Returns the known (or best-known) optimal solution, if available.
+    ///
+    /// The solution is read from enoppy's `get_global_optimum` if provided.
+    /// Otherwise, reference solutions are only known for the speed reducer, tension/compression
+    /// spring, pressure vessel, welded beam, and three-bar truss design problems.
+    pub fn optimal_solution(&self) -> Option<Vec<f64>> {
+        self.optimal_solution.clone()
+    }
+
+    /// Returns the nominal starting solution of the problem.
+    ///
+    /// This is the as-designed baseline if the Python problem provides one,
+    /// and the midpoint of the domain otherwise.
+    pub fn default_solution(&self) -> Vec<f64> {
+        self.baseline.clone().unwrap_or_else(|| self.midpoint())
+    }
+
+    /// Returns the category of the problem, which is only known for the RWCO 2020 problems.
+    pub fn category(&self) -> Option<ProblemCategory> {
+        if self.module == RWCO_2020 {
+            metadata::category(&self.name)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the citation of the source of the problem, e.g. for reporting it alongside
+    /// results.
+    ///
+    /// The classic engineering problems cite their original source and all other RWCO 2020
+    /// problems cite the suite by Kumar et al. (2020), while problems from other modules
+    /// have no recorded reference.
+    pub fn reference(&self) -> Option<String> {
+        if self.module == RWCO_2020 {
+            metadata::reference(&self.name).map(str::to_string)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the type of each decision variable.
+    ///
+    /// Only the multiple disk clutch brake and planetary gear train design problems
+    /// are known to have integer variables, all other variables are reported as continuous.
+    pub fn variable_types(&self) -> Vec<VarType> {
+        self.variable_types.clone()
+    }
+
+    /// Returns the names of the decision variables, e.g. `["Ts", "Th", "R", "L"]` for the
+    /// pressure vessel design problem.
+    ///
+    /// Names are read from the Python problem if provided, and otherwise only known for the
+    /// classic mechanical design problems.
+    pub fn variable_names(&self) -> Option<Vec<String>> {
+        self.variable_names.clone()
+    }
+
+    /// Returns whether the problem is naturally formulated as minimization problem,
+    /// which is the case for all RWCO 2020 problems.
+    ///
+    /// As mahf always minimizes, the evaluator negates the objective values of
+    /// maximization problems, so lower values are better in any case.
+    /// Note that [`Self::global_optimum_value`] is reported in the original direction.
+    pub fn is_minimization(&self) -> bool {
+        self.minimize
+    }
+
+    /// Returns the number of inequality constraints.
+    pub fn num_inequality_constraints(&self) -> usize {
+        self.num_inequality
+    }
+
+    /// Returns the number of equality constraints.
+    pub fn num_equality_constraints(&self) -> usize {
+        self.num_equality
+    }
+
+    /// Returns the tolerance within which equality residuals count as satisfied.
+    ///
+    /// It is read from the Python problem if provided, and [`DEFAULT_EQUALITY_TOLERANCE`]
+    /// otherwise.
+    pub fn equality_tolerance(&self) -> f64 {
+        self.equality_tolerance
+    }
+
+    /// Returns the total number of constraints, which is zero for box-constrained problems.
+    pub fn num_constraints(&self) -> usize {
+        self.num_inequality + self.num_equality
+    }
+
+    /// Returns a multi-line human-readable summary of the problem.
+    pub fn summary(&self) -> String {
+        let domain = self
+            .domain
+            .iter()
+            .map(|range| format!("[{}, {}]", range.start, range.end))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let optimum = self
+            .global_optimum
+            .map_or_else(|| "unknown".to_string(), |value| value.to_string());
+
+        format!(
+            "Name: {}\n\
+            Module: {}\n\
+            Dimension: {}\n\
+            Constraints: {} inequality, {} equality\n\
+            Domain: {domain}\n\
+            Known optimum: {optimum}",
+            self.name, self.module, self.dim, self.num_inequality, self.num_equality,
+        )
+    }
+
+    /// Creates an evaluator for this problem, which reuses the already constructed Python object.
+    ///
+    /// This acquires the GIL to share the Python object, so it blocks while another thread
+    /// holds the GIL.
+    pub fn evaluator(&self) -> EngineeringOptimizationEvaluator {
+        EngineeringOptimizationEvaluator::new(self)
+    }
+
+    /// Suggests a penalty coefficient for [`EngineeringOptimizationEvaluator::with_penalty`].
+    ///
+    /// The heuristic is `1000 * max(|f(c)|, 1)`, where `f(c)` is the objective value at the
+    /// midpoint `c` of the domain, or the known optimum if that evaluation fails.
+    /// A violation of `1e-3` then costs about as much as the objective value, so the
+    /// penalty neither vanishes next to the objective nor dominates it for tiny violations.
+    /// The midpoint is evaluated once per call.
+    pub fn suggested_penalty(&self) -> f64 {
+        let objective = self.evaluator().evaluate_full(&self.midpoint()).objective;
+        let scale = Some(objective)
+            .filter(|objective| objective.is_finite())
+            .or(self.global_optimum)
+            .map_or(1.0, |objective| objective.abs().max(1.0));
+        1000.0 * scale
+    }
+
+    /// Estimates the typical magnitude of objective values, e.g. as reference for relative
+    /// convergence thresholds.
+    ///
+    /// The estimate is the median absolute objective value of 16 random solutions, which
+    /// are sampled with a fixed seed.
+    /// Only feasible samples are considered if there are any, and samples that fail to
+    /// evaluate are ignored.
+    /// If no sample can be evaluated, the absolute known optimum or `1.0` is returned.
+    ///
+    /// The estimate is computed on the first call and shared between clones.
+    pub fn objective_scale(&self) -> f64 {
+        if let Some(scale) = self.objective_scale.get() {
+            return *scale;
+        }
+        // Initializing the cell while acquiring the GIL could deadlock with a thread that
+        // holds the GIL and waits for the cell, so concurrent first calls estimate twice.
+        let scale = self.estimate_objective_scale();
+        *self.objective_scale.get_or_init(|| scale)
+    }
+
+    /// Implements [`Self::objective_scale`] without caching.
+    fn estimate_objective_scale(&self) -> f64 {
+        const SAMPLES: usize = 16;
+
+        // A zero penalty makes the fitness the objective value, while the violation tells
+        // feasible samples apart.
+        let evaluator = self.evaluator().with_penalty(0.0);
+        let mut rng = StdRng::seed_from_u64(0);
+        let solutions: Vec<_> = (0..SAMPLES)
+            .map(|_| Cow::Owned(self.random_solution(&mut rng)))
+            .collect();
+        let samples: Vec<_> =
+            Python::with_gil(|py| evaluator.evaluate_prepared_batch(py, &solutions))
+                .into_iter()
+                .filter(|(objective, _)| objective.is_finite())
+                .collect();
+
+        let feasible = |violation: &Option<ConstraintViolation>| {
+            violation.is_some_and(|violation| violation.is_feasible())
+        };
+        let any_feasible = samples.iter().any(|(_, violation)| feasible(violation));
+        let mut magnitudes: Vec<_> = samples
+            .iter()
+            .filter(|(_, violation)| feasible(violation) || !any_feasible)
+            .map(|(objective, _)| objective.abs())
+            .collect();
+        magnitudes.sort_by(f64::total_cmp);
+
+        match magnitudes.len() {
+            0 => self.global_optimum.map_or(1.0, f64::abs),
+            n if n % 2 == 0 => (magnitudes[n / 2 - 1] + magnitudes[n / 2]) / 2.0,
+            n => magnitudes[n / 2],
+        }
+    }
+
+    pub fn try_heat_exchanger_network_design_case1() -> ExecResult<Self> {
+        RwcoProblem::HeatExchangerNetworkDesignCase1.try_build()
     }
 
     pub fn heat_exchanger_network_design_case1() -> Self {
-        Self::new("HeatExchangerNetworkDesignCase1Problem").unwrap()
+        RwcoProblem::HeatExchangerNetworkDesignCase1.build()
+    }
+
+    pub fn try_heat_exchanger_network_design_case2() -> ExecResult<Self> {
+        RwcoProblem::HeatExchangerNetworkDesignCase2.try_build()
     }
 
     pub fn heat_exchanger_network_design_case2() -> Self {
-        Self::new("HeatExchangerNetworkDesignCase2Problem").unwrap()
+        RwcoProblem::HeatExchangerNetworkDesignCase2.build()
+    }
+
+    pub fn try_haverly_pooling() -> ExecResult<Self> {
+        RwcoProblem::HaverlyPooling.try_build()
     }
 
     pub fn haverly_pooling() -> Self {
-        Self::new("HaverlyPoolingProblem").unwrap()
+        RwcoProblem::HaverlyPooling.build()
+    }
+
+    pub fn try_blending_pooling_separation() -> ExecResult<Self> {
+        RwcoProblem::BlendingPoolingSeparation.try_build()
     }
 
     pub fn blending_pooling_separation() -> Self {
-        Self::new("BlendingPoolingSeparationProblem").unwrap()
+        RwcoProblem::BlendingPoolingSeparation.build()
+    }
+
+    pub fn try_propane_isobutane_n_butane_nonsharp_separation() -> ExecResult<Self> {
+        RwcoProblem::PropaneIsobutaneNButaneNonsharpSeparation.try_build()
     }
 
     pub fn propane_isobutane_n_butane_nonsharp_separation() -> Self {
-        Self::new("PropaneIsobutaneNButaneNonsharpSeparationProblem").unwrap()
+        RwcoProblem::PropaneIsobutaneNButaneNonsharpSeparation.build()
+    }
+
+    pub fn try_optimal_operation_alkylation_unit() -> ExecResult<Self> {
+        RwcoProblem::OptimalOperationAlkylationUnit.try_build()
     }
 
     pub fn optimal_operation_alkylation_unit() -> Self {
-        Self::new("OptimalOperationAlkylationUnitProblem").unwrap()
+        RwcoProblem::OptimalOperationAlkylationUnit.build()
+    }
+
+    pub fn try_reactor_network_design() -> ExecResult<Self> {
+        RwcoProblem::ReactorNetworkDesign.try_build()
     }
 
     pub fn reactor_network_design() -> Self {
-        Self::new("ReactorNetworkDesignProblem").unwrap()
+        RwcoProblem::ReactorNetworkDesign.build()
+    }
+
+    pub fn try_process_synthesis_01() -> ExecResult<Self> {
+        RwcoProblem::ProcessSynthesis01.try_build()
     }
 
     pub fn process_synthesis_01() -> Self {
-        Self::new("ProcessSynthesis01Problem").unwrap()
+        RwcoProblem::ProcessSynthesis01.build()
+    }
+
+    pub fn try_process_synthesis_02() -> ExecResult<Self> {
+        RwcoProblem::ProcessSynthesis02.try_build()
     }
 
     pub fn process_synthesis_02() -> Self {
-        Self::new("ProcessSynthesis02Problem").unwrap()
+        RwcoProblem::ProcessSynthesis02.build()
+    }
+
+    pub fn try_process_design() -> ExecResult<Self> {
+        RwcoProblem::ProcessDesign.try_build()
     }
 
     pub fn process_design() -> Self {
-        Self::new("ProcessDesignProblem").unwrap()
+        RwcoProblem::ProcessDesign.build()
+    }
+
+    pub fn try_process_synthesis_and_design() -> ExecResult<Self> {
+        RwcoProblem::ProcessSynthesisAndDesign.try_build()
     }
 
     pub fn process_synthesis_and_design() -> Self {
-        Self::new("ProcessSynthesisAndDesignProblem").unwrap()
+        RwcoProblem::ProcessSynthesisAndDesign.build()
+    }
+
+    pub fn try_process_flow_sheeting() -> ExecResult<Self> {
+        RwcoProblem::ProcessFlowSheeting.try_build()
     }
 
     pub fn process_flow_sheeting() -> Self {
-        Self::new("ProcessFlowSheetingProblem").unwrap()
+        RwcoProblem::ProcessFlowSheeting.build()
+    }
+
+    pub fn try_two_reactor() -> ExecResult<Self> {
+        RwcoProblem::TwoReactor.try_build()
     }
 
     pub fn two_reactor() -> Self {
-        Self::new("TwoReactorProblem").unwrap()
+        RwcoProblem::TwoReactor.build()
+    }
+
+    pub fn try_multi_product_batch_plant() -> ExecResult<Self> {
+        RwcoProblem::MultiProductBatchPlant.try_build()
     }
 
     pub fn multi_product_batch_plant() -> Self {
-        Self::new("MultiProductBatchPlantProblem").unwrap()
+        RwcoProblem::MultiProductBatchPlant.build()
+    }
+
+    pub fn try_weight_minimization_speed_reducer() -> ExecResult<Self> {
+        RwcoProblem::WeightMinimizationSpeedReducer.try_build()
     }
 
     pub fn weight_minimization_speed_reducer() -> Self {
-        Self::new("WeightMinimizationSpeedReducerProblem").unwrap()
+        RwcoProblem::WeightMinimizationSpeedReducer.build()
+    }
+
+    pub fn try_optimal_design_industrial_refrigeration_system() -> ExecResult<Self> {
+        RwcoProblem::OptimalDesignIndustrialRefrigerationSystem.try_build()
     }
 
     pub fn optimal_design_industrial_refrigeration_system() -> Self {
-        Self::new("OptimalDesignIndustrialRefrigerationSystemProblem").unwrap()
+        RwcoProblem::OptimalDesignIndustrialRefrigerationSystem.build()
+    }
+
+    pub fn try_tension_compression_spring_design() -> ExecResult<Self> {
+        RwcoProblem::TensionCompressionSpringDesign.try_build()
     }
 
     pub fn tension_compression_spring_design() -> Self {
-        Self::new("TensionCompressionSpringDesignProblem").unwrap()
+        RwcoProblem::TensionCompressionSpringDesign.build()
+    }
+
+    pub fn try_pressure_vessel_design() -> ExecResult<Self> {
+        RwcoProblem::PressureVesselDesign.try_build()
     }
 
     pub fn pressure_vessel_design() -> Self {
-        Self::new("PressureVesselDesignProblem").unwrap()
+        RwcoProblem::PressureVesselDesign.build()
+    }
+
+    pub fn try_welded_beam_design() -> ExecResult<Self> {
+        RwcoProblem::WeldedBeamDesign.try_build()
     }
 
     pub fn welded_beam_design() -> Self {
-        Self::new("WeldedBeamDesignProblem").unwrap()
+        RwcoProblem::WeldedBeamDesign.build()
+    }
+
+    pub fn try_three_bar_truss_design() -> ExecResult<Self> {
+        RwcoProblem::ThreeBarTrussDesign.try_build()
     }
 
     pub fn three_bar_truss_design() -> Self {
-        Self::new("ThreeBarTrussDesignProblem").unwrap()
+        RwcoProblem::ThreeBarTrussDesign.build()
+    }
+
+    pub fn try_multiple_disk_clutch_brake_design() -> ExecResult<Self> {
+        RwcoProblem::MultipleDiskClutchBrakeDesign.try_build()
     }
 
     pub fn multiple_disk_clutch_brake_design() -> Self {
-        Self::new("MultipleDiskClutchBrakeDesignProblem").unwrap()
+        RwcoProblem::MultipleDiskClutchBrakeDesign.build()
+    }
+
+    pub fn try_planetary_gear_train_design() -> ExecResult<Self> {
+        RwcoProblem::PlanetaryGearTrainDesignOptimization.try_build()
     }
 
     pub fn planetary_gear_train_design() -> Self {
-        Self::new("PlanetaryGearTrainDesignOptimizationProblem").unwrap()
+        RwcoProblem::PlanetaryGearTrainDesignOptimization.build()
+    }
+
+    pub fn try_step_cone_pulley() -> ExecResult<Self> {
+        RwcoProblem::StepConePulley.try_build()
     }
 
     pub fn step_cone_pulley() -> Self {
-        Self::new("StepConePulleyProblem").unwrap()
+        RwcoProblem::StepConePulley.build()
+    }
+}
+
+/// Reads the optimization direction from the `minmax` attribute, defaulting to minimization.
+fn py_is_minimization(problem: &PyAny) -> bool {
+    problem
+        .getattr("minmax")
+        .and_then(|minmax| minmax.extract::<String>())
+        .map_or(true, |minmax| minmax != "max")
+}
+
+/// Rounds `value` to `sig_figs` significant figures.
+fn round_significant(value: f64, sig_figs: u32) -> f64 {
+    if value == 0.0 || !value.is_finite() {
+        return value;
+    }
+    let digits = sig_figs as i32 - 1 - value.abs().log10().floor() as i32;
+    // Scaling by the reciprocal is avoided to keep decimal values like 0.1 exact.
+    if digits >= 0 {
+        let factor = 10f64.powi(digits);
+        if factor.is_finite() {
+            (value * factor).round() / factor
+        } else {
+            value
+        }
+    } else {
+        let factor = 10f64.powi(-digits);
+        (value / factor).round() * factor
+    }
+}
+
+/// Samples a solution uniformly within `domain`.
+fn random_in<R: Rng + ?Sized>(domain: &[Range<f64>], rng: &mut R) -> Vec<f64> {
+    domain
+        .iter()
+        .map(|range| {
+            if range.is_empty() {
+                range.start
+            } else {
+                rng.gen_range(range.clone())
+            }
+        })
+        .collect()
+}
+
+/// Maps `u` from the unit hypercube into `domain`.
+fn from_unit(domain: &[Range<f64>], u: &[f64]) -> Vec<f64> {
+    u.iter()
+        .zip(domain)
+        .map(|(&u, range)| {
+            if range.is_empty() {
+                range.start
+            } else {
+                range.start + u * (range.end - range.start)
+            }
+        })
+        .collect()
+}
+
+/// Clamps `x` into `range`, including its end.
+fn clamp(x: f64, range: &Range<f64>) -> f64 {
+    x.max(range.start).min(range.end)
+}
+
+/// Returns `domain` with degenerate ranges `x..x` widened to `x..x.next_up()`, so that
+/// uniformly sampling from each range yields `x` instead of panicking.
+fn sampleable(domain: &[Range<f64>]) -> Vec<Range<f64>> {
+    domain
+        .iter()
+        .map(|range| {
+            if range.is_empty() {
+                range.start..range.start.next_up()
+            } else {
+                range.clone()
+            }
+        })
+        .collect()
+}
+
+/// Reads the nominal starting solution, if the problem provides one with dimension `dim`.
+fn py_baseline(problem: &PyAny, dim: usize) -> Option<Vec<f64>> {
+    ["x0", "initial_solution", "default_solution"]
+        .iter()
+        .find_map(|attr| problem.getattr(*attr).ok()?.extract::<Vec<f64>>().ok())
+        .filter(|solution| solution.len() == dim)
+}
+
+/// Reads the variable names if the problem provides them.
+fn py_variable_names(problem: &PyAny) -> Option<Vec<String>> {
+    ["variable_names", "var_names", "x_names"]
+        .iter()
+        .find_map(|attr| problem.getattr(*attr).ok()?.extract().ok())
+}
+
+/// Reads the acceptance tolerance for equality constraints, defaulting to
+/// [`DEFAULT_EQUALITY_TOLERANCE`].
+fn py_equality_tolerance(problem: &PyAny) -> f64 {
+    ["epsilon", "eps", "equality_tolerance"]
+        .iter()
+        .find_map(|attr| problem.getattr(*attr).ok()?.extract::<f64>().ok())
+        .filter(|tolerance| *tolerance >= 0.0)
+        .unwrap_or(DEFAULT_EQUALITY_TOLERANCE)
+}
+
+/// Reads the first of the integer `attrs` that the problem provides.
+fn py_count(problem: &PyAny, attrs: &[&str]) -> Option<usize> {
+    attrs
+        .iter()
+        .find_map(|attr| problem.getattr(*attr).ok()?.extract::<usize>().ok())
+}
+
+/// Reads the optimum from enoppy's `get_global_optimum`, which returns
+/// either the optimal value or a `(solution, value)` pair.
+fn py_global_optimum(problem: &PyAny) -> (Option<Vec<f64>>, Option<f64>) {
+    let Ok(optimum) = problem.call_method0("get_global_optimum") else {
+        return (None, None);
+    };
+    if let Ok(value) = optimum.extract::<f64>() {
+        (None, Some(value))
+    } else if let Ok((solution, value)) = optimum.extract::<(Vec<f64>, f64)>() {
+        (Some(solution), Some(value))
+    } else {
+        (None, None)
+    }
+}
+
+/// Problems are identified by their name and module, regardless of their cached metadata.
+impl PartialEq for EngineeringOptimizationProblem {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.module == other.module
+    }
+}
+
+impl Eq for EngineeringOptimizationProblem {}
+
+impl Hash for EngineeringOptimizationProblem {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.module.hash(state);
+    }
+}
+
+impl fmt::Display for EngineeringOptimizationProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lower = self
+            .domain
+            .iter()
+            .map(|r| r.start)
+            .fold(f64::INFINITY, f64::min);
+        let upper = self
+            .domain
+            .iter()
+            .map(|r| r.end)
+            .fold(f64::NEG_INFINITY, f64::max);
+        write!(
+            f,
+            "{} (dimension: {}, constraints: {}, bounds: [{lower}, {upper}])",
+            self.name,
+            self.dim,
+            self.num_constraints()
+        )
     }
 }
 
@@ -135,6 +1193,17 @@ impl Problem for EngineeringOptimizationProblem {
     }
 }
 
+/// Problems without a known optimum report [`f64::INFINITY`], so distance-based
+/// conditions never consider them solved.
+impl KnownOptimumProblem for EngineeringOptimizationProblem {
+    fn known_optimum(&self) -> SingleObjective {
+        self.global_optimum
+            .map(|value| if self.minimize { value } else { -value })
+            .and_then(|value| SingleObjective::try_from(value).ok())
+            .unwrap_or_default()
+    }
+}
+
 impl VectorProblem for EngineeringOptimizationProblem {
     type Element = f64;
 
@@ -143,29 +1212,1359 @@ impl VectorProblem for EngineeringOptimizationProblem {
     }
 }
 
+/// Degenerate ranges of fixed variables are widened to the next larger float, as mahf
+/// samples initial solutions from each range, which panics for empty ranges.
 impl LimitedVectorProblem for EngineeringOptimizationProblem {
     fn domain(&self) -> Vec<Range<Self::Element>> {
-        self.domain.clone()
+        sampleable(&self.domain)
+    }
+}
+
+/// The total constraint violation of a solution.
+///
+/// It is computed as the sum of all positive inequality constraint values plus the
+/// absolute values of all equality constraint residuals, i.e. zero means feasible.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct ConstraintViolation(pub f64);
+
+impl ConstraintViolation {
+    /// Computes the total violation from raw constraint values, where the last
+    /// `num_equality` values are treated as equality constraints.
+    pub fn from_constraints(constraints: &[f64], num_equality: usize) -> Self {
+        Self::with_equality_tolerance(constraints, num_equality, 0.0)
+    }
+
+    /// Computes the total violation like [`Self::from_constraints`], but equality residuals
+    /// with an absolute value of at most `equality_tolerance` count as satisfied.
+    pub fn with_equality_tolerance(
+        constraints: &[f64],
+        num_equality: usize,
+        equality_tolerance: f64,
+    ) -> Self {
+        Self(
+            Self::per_constraint(constraints, num_equality, equality_tolerance)
+                .iter()
+                .sum(),
+        )
+    }
+
+    /// Computes the violation of each constraint, i.e. the positive part of inequality
+    /// constraint values and the absolute value of equality constraint residuals exceeding
+    /// `equality_tolerance`.
+    pub fn per_constraint(
+        constraints: &[f64],
+        num_equality: usize,
+        equality_tolerance: f64,
+    ) -> Vec<f64> {
+        let split = constraints.len().saturating_sub(num_equality);
+        let (inequality, equality) = constraints.split_at(split);
+        let equality = equality.iter().map(|h| {
+            if h.abs() <= equality_tolerance {
+                0.0
+            } else {
+                h.abs()
+            }
+        });
+        inequality
+            .iter()
+            .map(|g| g.max(0.0))
+            .chain(equality)
+            .collect()
+    }
+
+    pub fn is_feasible(&self) -> bool {
+        self.0 <= 0.0
     }
 }
 
+/// The objective value and raw constraint values of a solution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Evaluation {
+    /// The objective value, negated for maximization problems.
+    pub objective: f64,
+    /// The inequality constraint values, where positive values are violations.
+    pub inequality: Vec<f64>,
+    /// The equality constraint residuals.
+    pub equality: Vec<f64>,
+}
+
+impl Evaluation {
+    /// Computes the total [`ConstraintViolation`], where equality residuals within `tol`
+    /// count as satisfied.
+    ///
+    /// Pass [`EngineeringOptimizationEvaluator::equality_tolerance`] as `tol` to be consistent
+    /// with the recorded violations.
+    pub fn violation(&self, tol: f64) -> ConstraintViolation {
+        let num_equality = self.equality.len();
+        let constraints = [self.inequality.as_slice(), &self.equality].concat();
+        ConstraintViolation::with_equality_tolerance(&constraints, num_equality, tol)
+    }
+
+    /// Returns whether all inequality constraints are satisfied and all equality
+    /// residuals are within `tol`.
+    pub fn is_feasible(&self, tol: f64) -> bool {
+        self.inequality.iter().all(|&g| g <= 0.0) && self.equality.iter().all(|h| h.abs() <= tol)
+    }
+}
+
+/// The objective value of a solution together with the dimensions in which it violates
+/// the bounds, see [`EngineeringOptimizationEvaluator::evaluate_with_bounds`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoundedEvaluation {
+    /// The objective value, as returned by [`EngineeringOptimizationEvaluator::evaluate_one`].
+    pub objective: f64,
+    /// The indices of the coordinates outside of the domain, in increasing order.
+    pub out_of_bounds: Vec<usize>,
+}
+
+/// Statistics of the total [`ConstraintViolation`]s of a population, see
+/// [`EngineeringOptimizationEvaluator::violation_summary`].
+///
+/// All values are zero for an empty population.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ViolationSummary {
+    pub min: f64,
+    pub mean: f64,
+    pub max: f64,
+    /// The fraction of feasible solutions, between zero and one.
+    pub feasible_fraction: f64,
+}
+
+impl ViolationSummary {
+    pub fn from_violations(violations: &[ConstraintViolation]) -> Self {
+        if violations.is_empty() {
+            return Self::default();
+        }
+        let n = violations.len() as f64;
+        let values = violations.iter().map(|violation| violation.0);
+        Self {
+            min: values.clone().fold(f64::INFINITY, f64::min),
+            mean: values.clone().sum::<f64>() / n,
+            max: values.fold(f64::NEG_INFINITY, f64::max),
+            feasible_fraction: violations.iter().filter(|v| v.is_feasible()).count() as f64 / n,
+        }
+    }
+}
+
+/// The [`ConstraintViolation`]s of the individuals of the last evaluated batch,
+/// in the same order as the individuals.
+///
+/// This state is inserted by [`EngineeringOptimizationEvaluator`] if enabled
+/// with [`EngineeringOptimizationEvaluator::with_constraint_violation`].
+#[derive(Clone, Debug, Default, Tid)]
+pub struct ConstraintViolations(pub Vec<ConstraintViolation>);
+
+impl CustomState<'_> for ConstraintViolations {}
+
+/// Marks that the evaluation budget of an [`EngineeringOptimizationEvaluator`] is exhausted.
+///
+/// This state is inserted once a batch could not be evaluated completely, see
+/// [`EngineeringOptimizationEvaluator::with_evaluation_budget`].
+#[derive(Clone, Copy, Debug, Default, Tid)]
+pub struct BudgetExhausted;
+
+impl CustomState<'_> for BudgetExhausted {}
+
+/// The objective value of a solution together with whether it satisfies all constraints.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Feasibility {
+    /// The objective value, negated for maximization problems.
+    pub objective: f64,
+    /// Whether the total violation is zero.
+    pub feasible: bool,
+    /// The total [`ConstraintViolation`].
+    pub total_violation: f64,
+}
+
+/// The [`Feasibility`] of the individual with the best objective value evaluated so far,
+/// i.e. of the individual that mahf tracks as best individual.
+///
+/// Its objective is the one assigned to the individual, which includes the penalty if enabled.
+///
+/// This state is inserted by [`EngineeringOptimizationEvaluator`] if enabled
+/// with [`EngineeringOptimizationEvaluator::with_feasibility_tracking`].
+/// With the `serde` feature, it can be logged with `LogConfig::with_auto::<BestFeasibility>`.
+#[derive(Clone, Copy, Debug, Default, Tid)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BestFeasibility(pub Option<Feasibility>);
+
+impl CustomState<'_> for BestFeasibility {}
+
+/// Evaluator for [`EngineeringOptimizationProblem`]s.
+///
+/// # Thread safety
+///
+/// The evaluator is `Send + Sync` and can be moved to or shared between worker threads,
+/// e.g. for parallel population evaluation.
+/// Clones share the same underlying Python object.
+///
+/// Note that every Python call acquires the GIL, so evaluations from multiple threads are
+/// serialized and no true parallelism is achieved, apart from sections where enoppy or
+/// NumPy release the GIL internally.
+///
+/// # Precision
+///
+/// Solutions are always passed to Python as `float64` NumPy arrays, as they are copied from
+/// `f64` slices, and results are extracted as `f64`.
+/// If enoppy returns lower precision values, e.g. `float32`, a warning is logged, as the
+/// objective values then can't reproduce published results to many digits.
+///
+/// # Lifecycle
+///
+/// The evaluator keeps the Python instance alive, see the
+/// [lifecycle of problems](EngineeringOptimizationProblem#lifecycle) and [`Self::close`].
 #[derive(Clone)]
 pub struct EngineeringOptimizationEvaluator {
+    name: String,
+    api: PyApi,
     inner: PyObject,
+    num_inequality: usize,
+    num_equality: usize,
+    minimize: bool,
+    record_violation: bool,
+    track_feasibility: bool,
+    track_best: bool,
+    best: Arc<Mutex<BestSoFar>>,
+    repair: Option<RepairStrategy>,
+    penalty: Option<Penalty>,
+    nan_policy: NanPolicy,
+    objective_precision: Option<u32>,
+    batch_layout: BatchLayout,
+    vectorized: OnceLock<bool>,
+    domain: Arc<[Range<f64>]>,
+    clamp: bool,
+    variable_types: Vec<VarType>,
+    round: bool,
+    reference: Option<(Vec<f64>, f64)>,
+    stats: Arc<Mutex<EvaluationStats>>,
+    callback: Option<Arc<EvaluationCallback>>,
+    budget: Option<usize>,
+    timeout: Option<Duration>,
+    equality_tolerance: f64,
+}
+
+/// The penalty applied to the objective value in the penalty evaluation mode.
+#[derive(Clone, Debug)]
+enum Penalty {
+    /// A single coefficient for the total violation.
+    Uniform(f64),
+    /// One coefficient per constraint.
+    Weighted(Arc<[f64]>),
+}
+
+impl Penalty {
+    fn apply(&self, constraints: &[f64], num_equality: usize, equality_tolerance: f64) -> f64 {
+        let violations =
+            ConstraintViolation::per_constraint(constraints, num_equality, equality_tolerance);
+        match self {
+            Penalty::Uniform(rho) => rho * violations.iter().sum::<f64>(),
+            Penalty::Weighted(weights) => violations
+                .iter()
+                .zip(weights.iter())
+                .map(|(violation, weight)| weight * violation)
+                .sum(),
+        }
+    }
 }
 
+/// A callback observing each evaluated solution and its objective value.
+type EvaluationCallback = dyn Fn(&[f64], f64) + Send + Sync;
+
 impl EngineeringOptimizationEvaluator {
     pub fn new(problem: &EngineeringOptimizationProblem) -> Self {
+        Python::with_gil(|py| Self {
+            name: problem.name.clone(),
+            api: problem.api,
+            inner: problem.inner.clone_ref(py),
+            num_inequality: problem.num_inequality,
+            num_equality: problem.num_equality,
+            minimize: problem.minimize,
+            record_violation: false,
+            track_feasibility: false,
+            track_best: false,
+            best: Arc::default(),
+            repair: None,
+            penalty: None,
+            nan_policy: NanPolicy::default(),
+            objective_precision: None,
+            batch_layout: BatchLayout::default(),
+            vectorized: OnceLock::new(),
+            domain: problem.domain.clone(),
+            clamp: false,
+            variable_types: problem.variable_types.clone(),
+            round: false,
+            reference: problem.optimal_solution.clone().zip(problem.global_optimum),
+            stats: Arc::default(),
+            callback: None,
+            budget: None,
+            timeout: None,
+            equality_tolerance: problem.equality_tolerance,
+        })
+    }
+
+    /// Returns whether this evaluator uses the same Python object as `problem`.
+    pub fn shares_instance_with(&self, problem: &EngineeringOptimizationProblem) -> bool {
+        self.inner.is(&problem.inner)
+    }
+
+    /// Drops this evaluator while holding the GIL, which releases its reference to the
+    /// Python instance immediately, see [`EngineeringOptimizationProblem::close`].
+    pub fn close(self) {
+        Python::with_gil(|_| drop(self));
+    }
+
+    /// Enables or disables inserting the [`ConstraintViolations`] of each evaluated
+    /// batch into the state.
+    ///
+    /// This requires an additional Python call per individual and is disabled by default.
+    pub fn with_constraint_violation(mut self, enabled: bool) -> Self {
+        self.record_violation = enabled;
+        self
+    }
+
+    /// Enables or disables keeping the [`BestFeasibility`] up to date in the state.
+    ///
+    /// Like [`Self::with_constraint_violation`], this requires an additional Python call
+    /// per individual and is disabled by default.
+    pub fn with_feasibility_tracking(mut self, enabled: bool) -> Self {
+        self.track_feasibility = enabled;
+        self
+    }
+
+    /// Repairs infeasible solutions with `strategy` during [`Evaluate`], replacing the
+    /// solution of the individual with the repaired one and assigning its objective value.
+    ///
+    /// Whether each individual was repaired is inserted into the state as [`Repaired`].
+    /// Every repaired candidate costs an additional evaluation, which also counts towards
+    /// the [evaluation budget](Self::with_evaluation_budget), and repairs stop once it
+    /// is exhausted.
+    pub fn with_repair(mut self, strategy: RepairStrategy) -> Self {
+        self.repair = Some(strategy);
+        self
+    }
+
+    /// Enables the penalty evaluation mode with penalty coefficient `rho`.
+    ///
+    /// In this mode, the objective and constraints are queried separately from Python,
+    /// and the objective value is computed as `objective + rho * violation`, where
+    /// `violation` is the total [`ConstraintViolation`].
+    /// This makes the objective independent of the penalty applied by the installed enoppy version.
+    pub fn with_penalty(mut self, rho: f64) -> Self {
+        self.penalty = Some(Penalty::Uniform(rho));
+        self
+    }
+
+    /// Enables the penalty evaluation mode like [`Self::with_penalty`], but with one
+    /// coefficient per constraint, which are applied to the individual violations.
+    ///
+    /// Constraints without a coefficient are not penalized.
+    /// See [`Self::default_penalty_weights`] for deriving coefficients from the problem.
+    pub fn with_weighted_penalty(mut self, weights: Vec<f64>) -> Self {
+        self.penalty = Some(Penalty::Weighted(weights.into()));
+        self
+    }
+
+    /// Derives per-constraint penalty coefficients by evaluating the constraints of
+    /// `samples` solutions drawn uniformly from the domain.
+    ///
+    /// Each coefficient is `rho` divided by the mean absolute value of its constraint,
+    /// so that constraints of all magnitudes are penalized comparably.
+    /// The magnitudes are sampled because the bounds of the domain say nothing about the
+    /// scale of the constraint values, which are often products or quotients of variables.
+    ///
+    /// If `samples` is zero or no sample could be evaluated, every coefficient is `rho`.
+    pub fn default_penalty_weights<R: Rng + ?Sized>(
+        &self,
+        rho: f64,
+        samples: usize,
+        rng: &mut R,
+    ) -> Vec<f64> {
+        let num_constraints = self.num_inequality + self.num_equality;
+        let mut magnitudes = vec![0.0; num_constraints];
+        let mut evaluated = 0;
+
+        Python::with_gil(|py| {
+            for _ in 0..samples {
+                let solution = self
+                    .prepare_solution(&random_in(&self.domain, rng))
+                    .into_owned();
+                let np_solution = PyArray1::from_slice(py, &solution);
+                let Ok(constraints) = self.guarded_constraints(py, np_solution) else {
+                    continue;
+                };
+                magnitudes.resize(magnitudes.len().max(constraints.len()), 0.0);
+                for (magnitude, c) in magnitudes.iter_mut().zip(constraints) {
+                    *magnitude += c.abs();
+                }
+                evaluated += 1;
+            }
+        });
+        if evaluated == 0 {
+            return vec![rho; num_constraints];
+        }
+
+        magnitudes
+            .into_iter()
+            .map(|sum| {
+                let mean = sum / evaluated as f64;
+                if mean > 0.0 && mean.is_finite() {
+                    rho / mean
+                } else {
+                    rho
+                }
+            })
+            .collect()
+    }
+
+    /// Sets the tolerance within which equality residuals count as satisfied, overriding
+    /// [`EngineeringOptimizationProblem::equality_tolerance`].
+    ///
+    /// It applies to recorded violations, penalties, and [`Self::constraint_violation`].
+    pub fn with_equality_tolerance(mut self, tolerance: f64) -> Self {
+        self.equality_tolerance = tolerance;
+        self
+    }
+
+    pub fn equality_tolerance(&self) -> f64 {
+        self.equality_tolerance
+    }
+
+    /// Limits the number of evaluations performed by [`Evaluate::evaluate`] to `max_evaluations`.
+    ///
+    /// Evaluations are counted by the [`EvaluationStats`], which are shared between clones,
+    /// so resetting them also resets the budget.
+    /// If a batch exceeds the remaining budget, only its leading individuals are evaluated,
+    /// all others receive an infinite objective value, and [`BudgetExhausted`] is inserted
+    /// into the state.
+    pub fn with_evaluation_budget(mut self, max_evaluations: usize) -> Self {
+        self.budget = Some(max_evaluations);
+        self
+    }
+
+    /// Aborts Python calls taking longer than `timeout`, which then result in an objective
+    /// value of [`f64::INFINITY`], or an infinite constraint value for calls evaluating the
+    /// constraints, e.g. [`Self::evaluate_constraints`] and [`Self::evaluate_full`].
+    ///
+    /// The timeout is enforced by a watchdog thread raising a `TimeoutError` in Python.
+    /// Due to Python's cooperative threading, the exception is only raised between
+    /// bytecode instructions, so hanging native code is not interrupted until it returns.
+    /// Starting the watchdog adds some overhead to every call.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Returns the number of evaluations left, if a budget is set.
+    pub fn remaining_budget(&self) -> Option<usize> {
+        self.budget
+            .map(|budget| budget.saturating_sub(self.stats().count))
+    }
+
+    /// Registers a `callback` that is invoked with the solution and objective value of
+    /// every individual evaluated by [`Evaluate::evaluate`], e.g. for live plotting.
+    ///
+    /// Clones of the evaluator share the callback.
+    pub fn on_evaluate(mut self, callback: impl Fn(&[f64], f64) + Send + Sync + 'static) -> Self {
+        self.callback = Some(Arc::new(callback));
+        self
+    }
+
+    /// Sets how NaN and negative infinite objective values are handled.
+    ///
+    /// Errors raised in Python always result in [`f64::INFINITY`], except in
+    /// [`Self::try_evaluate`].
+    pub fn with_nan_policy(mut self, policy: NanPolicy) -> Self {
+        self.nan_policy = policy;
+        self
+    }
+
+    /// Rounds objective values returned from Python to `sig_figs` significant figures,
+    /// e.g. for comparing against tabulated results or for more hits in a
+    /// [`CachingEvaluator`].
+    ///
+    /// The penalty of [`Self::with_penalty`] is added afterwards and not rounded.
+    /// By default, objective values are used with full precision.
+    pub fn with_objective_precision(mut self, sig_figs: u32) -> Self {
+        self.objective_precision = Some(sig_figs.max(1));
+        self
+    }
+
+    /// Sets the [`BatchLayout`] expected by the vectorized evaluation of the problem.
+    ///
+    /// With the wrong layout, the number of returned values usually differs from the batch
+    /// size, and otherwise the first value usually disagrees with a scalar evaluation,
+    /// which both disable vectorized evaluation.
+    /// By default, solutions are passed as rows.
+    pub fn with_batch_layout(mut self, layout: BatchLayout) -> Self {
+        self.batch_layout = layout;
+        self
+    }
+
+    /// Enables or disables clamping solutions into the domain before evaluating them.
+    ///
+    /// Only the copy passed to Python is clamped, and the solutions stored in
+    /// the individuals are left untouched.
+    pub fn clamp_to_domain(mut self, enabled: bool) -> Self {
+        self.clamp = enabled;
+        self
+    }
+
+    /// Enables or disables rounding integer and discrete variables to their closest
+    /// allowed value before evaluating them, see [`EngineeringOptimizationProblem::variable_types`].
+    ///
+    /// Only the copy passed to Python is rounded, and the solutions stored in
+    /// the individuals are left untouched.
+    pub fn round_to_variable_types(mut self, enabled: bool) -> Self {
+        self.round = enabled;
+        self
+    }
+
+    /// Returns the statistics of all evaluations performed so far.
+    ///
+    /// The statistics are shared between clones of the evaluator.
+    pub fn stats(&self) -> EvaluationStats {
+        *self.lock_stats()
+    }
+
+    /// Resets the evaluation statistics.
+    pub fn reset_stats(&self) {
+        *self.lock_stats() = EvaluationStats::default();
+    }
+
+    fn record_stats(&self, count: usize, elapsed: Duration) {
+        self.lock_stats().record(count, elapsed);
+    }
+
+    /// Locks the statistics, which stay valid even if another thread panicked while
+    /// holding the lock, as every update is a single assignment.
+    fn lock_stats(&self) -> MutexGuard<'_, EvaluationStats> {
+        self.stats.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Negates `value` for maximization problems, see
+    /// [`EngineeringOptimizationProblem::is_minimization`], and applies the [`NanPolicy`].
+    fn orient(&self, value: f64) -> PyResult<f64> {
+        let value = if self.minimize { value } else { -value };
+        let value = match self.objective_precision {
+            Some(sig_figs) => round_significant(value, sig_figs),
+            None => value,
+        };
+        self.nan_policy.apply(value, &self.name)
+    }
+
+    /// Returns the `solution` that is passed to Python, which is only copied if it
+    /// needs to be clamped or rounded.
+    fn prepare_solution<'a>(&self, solution: &'a [f64]) -> Cow<'a, [f64]> {
+        if !self.clamp && !self.round {
+            return Cow::Borrowed(solution);
+        }
+
+        let mut solution = solution.to_vec();
+        if self.clamp {
+            for (x, range) in solution.iter_mut().zip(self.domain.iter()) {
+                *x = clamp(*x, range);
+            }
+        }
+        if self.round {
+            for (x, var_type) in solution.iter_mut().zip(&self.variable_types) {
+                *x = var_type.round(*x);
+            }
+        }
+        Cow::Owned(solution)
+    }
+
+    /// Prepares `solution` like [`Self::prepare_solution`], or returns `None` with a warning
+    /// if it has the wrong dimension.
+    fn prepare_checked<'a>(&self, solution: &'a [f64]) -> Option<Cow<'a, [f64]>> {
+        if solution.len() != self.domain.len() {
+            warn!(
+                "skipping evaluation of `{}`: solution has dimension {}, but expected {}",
+                self.name,
+                solution.len(),
+                self.domain.len()
+            );
+            return None;
+        }
+        Some(self.prepare_solution(solution))
+    }
+
+    /// Evaluates all `solutions` with a single vectorized Python call.
+    ///
+    /// Returns `None` if the underlying problem does not support vectorized input,
+    /// which is detected on the first call and remembered afterwards.
+    fn evaluate_vectorized(&self, py: Python, solutions: &[&[f64]]) -> Option<Vec<f64>> {
+        if solutions.len() < 2 || self.vectorized.get() == Some(&false) {
+            return None;
+        }
+
+        // Solutions with invalid dimensions are handled by the per-solution fallback.
+        let dim = self.domain.len();
+        if solutions.iter().any(|solution| solution.len() != dim) {
+            return None;
+        }
+        let flat = solutions.iter().flat_map(|s| s.iter().copied()).collect();
+        let batch = Array2::from_shape_vec((solutions.len(), dim), flat).ok()?;
+
+        self.evaluate_vectorized_array(py, batch.into_pyarray(py), solutions[0], solutions.len())
+    }
+
+    /// Implements [`Self::evaluate_vectorized`] for a batch that was already converted
+    /// to a NumPy array with `count` rows of the correct dimension, starting with `first`.
+    ///
+    /// The batch is transposed for [`BatchLayout::ColumnMajorSolutions`].
+    /// When probing whether the problem supports vectorized input, the first value is
+    /// compared against a scalar evaluation of `first`, as problems that compute on the
+    /// coordinates `x[0], x[1], ...` return one value per coordinate, which would be
+    /// accepted for batches as large as the dimension.
+    /// The probe is not counted in the statistics or against the budget.
+    fn evaluate_vectorized_array(
+        &self,
+        py: Python,
+        np_batch: &PyAny,
+        first: &[f64],
+        count: usize,
+    ) -> Option<Vec<f64>> {
+        let start = Instant::now();
+        let fitness = self
+            .guarded(py, || {
+                let np_batch = match self.batch_layout {
+                    BatchLayout::RowMajorSolutions => np_batch,
+                    BatchLayout::ColumnMajorSolutions => np_batch.getattr("T")?,
+                };
+                self.api.fitness(self.inner.as_ref(py), np_batch)
+            })
+            .and_then(extract_values)
+            .ok()
+            .filter(|fitness| fitness.len() == count);
+        if fitness.is_some() {
+            self.record_stats(count, start.elapsed());
+        }
+
+        let fitness = fitness.filter(|fitness| {
+            self.vectorized.get().is_some() || self.matches_scalar(py, first, fitness[0])
+        });
+        self.vectorized.get_or_init(|| fitness.is_some());
+        // Invalid values rejected by the `NanPolicy` are reported by the per-solution fallback.
+        fitness?
+            .into_iter()
+            .map(|f| self.orient(f))
+            .collect::<PyResult<_>>()
+            .ok()
+    }
+
+    /// Returns whether the raw `fitness` returned by a vectorized call for `solution` agrees
+    /// with evaluating `solution` on its own, bypassing [`Self::record_stats`].
+    fn matches_scalar(&self, py: Python, solution: &[f64], fitness: f64) -> bool {
+        let np_solution = PyArray1::from_slice(py, solution);
+        let scalar = self.guarded(py, || self.try_evaluate_array_unguarded(py, np_solution));
+        let Ok((scalar, _)) = scalar else {
+            return false;
+        };
+        self.orient(fitness).is_ok_and(|fitness| {
+            fitness.total_cmp(&scalar).is_eq()
+                || (fitness - scalar).abs() <= 1e-9 * scalar.abs().max(1.0)
+        })
+    }
+
+    /// Evaluates the objective value of a single `solution`.
+    ///
+    /// Errors during evaluation, including solutions with the wrong dimension,
+    /// result in an objective value of [`f64::INFINITY`].
+    pub fn evaluate_one(&self, solution: &[f64]) -> f64 {
+        let solution = self.prepare_solution(solution);
+        Python::with_gil(|py| self.evaluate_prepared(py, &solution).0)
+    }
+
+    /// Returns [`Self::evaluate_one`] as a cost function, e.g. for optimization libraries
+    /// that expect a closure instead of a problem type.
+    ///
+    /// Each call of the closure acquires the GIL, and errors raised in Python result in
+    /// [`f64::INFINITY`] instead of being reported to the caller.
+    pub fn as_closure(&self) -> impl Fn(&[f64]) -> f64 + '_ {
+        |solution| self.evaluate_one(solution)
+    }
+
+    /// Evaluates the objective value of `solution` like [`Self::evaluate_one`], and reports
+    /// which coordinates are outside of the domain.
+    ///
+    /// The bounds are checked in Rust before clamping, so this costs no additional Python
+    /// call and reports violations even if [`Self::clamp_to_domain`] is enabled.
+    pub fn evaluate_with_bounds(&self, solution: &[f64]) -> BoundedEvaluation {
+        let out_of_bounds = solution
+            .iter()
+            .zip(self.domain.iter())
+            .enumerate()
+            .filter(|(_, (x, range))| **x < range.start || **x > range.end)
+            .map(|(i, _)| i)
+            .collect();
+        BoundedEvaluation {
+            objective: self.evaluate_one(solution),
+            out_of_bounds,
+        }
+    }
+
+    /// Evaluates the objective values of `solutions` without going through mahf,
+    /// using a single vectorized Python call if the problem supports it.
+    pub fn evaluate_batch(&self, solutions: &[Vec<f64>]) -> Vec<f64> {
+        let solutions: Vec<_> = solutions
+            .iter()
+            .map(|solution| self.prepare_solution(solution))
+            .collect();
+        Python::with_gil(|py| self.evaluate_prepared_batch(py, &solutions))
+            .into_iter()
+            .map(|(fitness, _)| fitness)
+            .collect()
+    }
+
+    /// Evaluates the objective values of `solutions` in a background thread, sending
+    /// `(index, objective)` pairs as soon as they are available, e.g. to update a progress bar.
+    ///
+    /// If the problem supports vectorized evaluation, all results are sent at once after a
+    /// single call, and otherwise one by one in order of `solutions`.
+    /// The GIL is acquired separately for every solution, so other threads can use Python
+    /// in between, and evaluation stops early once the receiver is dropped.
+    pub fn evaluate_streaming(&self, solutions: Vec<Vec<f64>>) -> Receiver<(usize, f64)> {
+        let (sender, receiver) = mpsc::channel();
+        let evaluator = self.clone();
+
+        thread::spawn(move || {
+            if !evaluator.computes_violation() {
+                let prepared: Vec<_> = solutions
+                    .iter()
+                    .map(|solution| evaluator.prepare_solution(solution))
+                    .collect();
+                let slices: Vec<_> = prepared.iter().map(AsRef::as_ref).collect();
+                let fitness = Python::with_gil(|py| evaluator.evaluate_vectorized(py, &slices));
+                if let Some(fitness) = fitness {
+                    for result in fitness.into_iter().enumerate() {
+                        if sender.send(result).is_err() {
+                            return;
+                        }
+                    }
+                    return;
+                }
+            }
+
+            for (i, solution) in solutions.iter().enumerate() {
+                if sender.send((i, evaluator.evaluate_one(solution))).is_err() {
+                    return;
+                }
+            }
+        });
+
+        receiver
+    }
+
+    /// Evaluates the objective values of the rows of `x`, see [`Self::evaluate_batch`].
+    ///
+    /// If no solution needs to be clamped or rounded, `x` is copied into NumPy at once and
+    /// passed to a single vectorized Python call.
+    /// Rows that fail to evaluate, e.g. because `x` has the wrong number of columns,
+    /// result in an objective value of [`f64::INFINITY`].
+    pub fn evaluate_matrix(&self, x: ArrayView2<f64>) -> Array1<f64> {
+        let direct = !self.clamp
+            && !self.round
+            && !self.computes_violation()
+            && x.nrows() >= 2
+            && x.ncols() == self.domain.len()
+            && self.vectorized.get() != Some(&false);
+
         Python::with_gil(|py| {
-            let problems = Python::import(py, "enoppy.paper_based.rwco_2020")?;
-            let py_problem_class = problems.getattr(problem.name.as_str())?;
-            let inner = py_problem_class.call0()?.into_py(py);
+            if direct {
+                let np_batch = PyArray2::from_array(py, &x);
+                let first = x.row(0).to_vec();
+                if let Some(fitness) =
+                    self.evaluate_vectorized_array(py, np_batch, &first, x.nrows())
+                {
+                    return Array1::from(fitness);
+                }
+            }
+
+            let rows: Vec<_> = x.rows().into_iter().map(|row| row.to_vec()).collect();
+            let solutions: Vec<_> = rows
+                .iter()
+                .map(|solution| self.prepare_solution(solution))
+                .collect();
+            self.evaluate_prepared_batch(py, &solutions)
+                .into_iter()
+                .map(|(fitness, _)| fitness)
+                .collect()
+        })
+    }
+
+    /// Evaluates the objective value of a `solution` given as ndarray view, see
+    /// [`Self::evaluate_one`].
+    ///
+    /// Contiguous views are passed to NumPy without an intermediate copy.
+    pub fn evaluate_view(&self, solution: ArrayView1<f64>) -> f64 {
+        match solution.as_slice() {
+            Some(solution) => self.evaluate_one(solution),
+            None => self.evaluate_one(&solution.to_vec()),
+        }
+    }
 
-            let evaluator = Self { inner };
+    /// Evaluates a single solution returned by [`Self::prepare_solution`].
+    ///
+    /// Returns the fitness and, if required by the configuration, the constraint violation.
+    ///
+    /// The solution is copied directly into a new NumPy array, so evaluating a borrowed
+    /// solution only allocates the array itself, e.g. a single 800 byte buffer for a
+    /// 100-dimensional problem instead of an additional `Vec` of the same size.
+    fn evaluate_prepared(
+        &self,
+        py: Python,
+        solution: &[f64],
+    ) -> (f64, Option<ConstraintViolation>) {
+        self.try_evaluate_prepared(py, solution)
+            .unwrap_or_else(|err| self.failed(err))
+    }
+
+    /// Implements [`Self::evaluate_prepared`], returning errors instead of infinite values.
+    fn try_evaluate_prepared(
+        &self,
+        py: Python,
+        solution: &[f64],
+    ) -> PyResult<(f64, Option<ConstraintViolation>)> {
+        if solution.len() != self.domain.len() {
+            return Err(PyValueError::new_err(format!(
+                "solution has dimension {}, but expected {}",
+                solution.len(),
+                self.domain.len()
+            )));
+        }
+
+        self.try_evaluate_array(py, PyArray1::from_slice(py, solution))
+    }
+
+    /// Evaluates a solution that was already converted to a NumPy array with the
+    /// correct dimension, see [`Self::evaluate_prepared`].
+    fn evaluate_array(
+        &self,
+        py: Python,
+        np_solution: &PyAny,
+    ) -> (f64, Option<ConstraintViolation>) {
+        self.try_evaluate_array(py, np_solution)
+            .unwrap_or_else(|err| self.failed(err))
+    }
+
+    /// Implements [`Self::evaluate_array`], returning errors instead of infinite values.
+    fn try_evaluate_array(
+        &self,
+        py: Python,
+        np_solution: &PyAny,
+    ) -> PyResult<(f64, Option<ConstraintViolation>)> {
+        let start = Instant::now();
+        let result = self.guarded(py, || self.try_evaluate_array_unguarded(py, np_solution));
+        self.record_stats(1, start.elapsed());
+        result
+    }
+
+    /// Implements [`Self::try_evaluate_array`] without the timeout.
+    fn try_evaluate_array_unguarded(
+        &self,
+        py: Python,
+        np_solution: &PyAny,
+    ) -> PyResult<(f64, Option<ConstraintViolation>)> {
+        let py_problem = self.inner.as_ref(py);
+
+        let constraints = if self.computes_violation() {
+            Some(self.api.constraints(py_problem, np_solution)?)
+        } else {
+            None
+        };
+        let violation = constraints.as_ref().map(|constraints| {
+            ConstraintViolation::with_equality_tolerance(
+                constraints,
+                self.num_equality,
+                self.equality_tolerance,
+            )
+        });
+
+        let fitness = match (&self.penalty, &constraints) {
+            (Some(penalty), Some(constraints)) => {
+                let objective = self.api.objective(py_problem, np_solution)?;
+                self.orient(objective)?
+                    + penalty.apply(constraints, self.num_equality, self.equality_tolerance)
+            }
+            _ => {
+                let fitness = self.api.fitness(py_problem, np_solution)?;
+                self.orient(extract_fitness(fitness)?)?
+            }
+        };
+
+        Ok((fitness, violation))
+    }
+
+    /// Logs a failed evaluation and returns the result assigned to it.
+    fn failed(&self, err: PyErr) -> (f64, Option<ConstraintViolation>) {
+        warn!("evaluation of `{}` failed: {err}", self.name);
+        let violation = self
+            .computes_violation()
+            .then_some(ConstraintViolation(f64::INFINITY));
+        (f64::INFINITY, violation)
+    }
+
+    /// Returns whether evaluations need to compute the constraint violation.
+    fn computes_violation(&self) -> bool {
+        self.constraint_aware() || self.track_best
+    }
+
+    /// Returns whether a mode that handles constraints is enabled.
+    fn constraint_aware(&self) -> bool {
+        self.record_violation
+            || self.track_feasibility
+            || self.repair.is_some()
+            || self.penalty.is_some()
+    }
+
+    /// Runs `f`, interrupting it if it exceeds the timeout set with [`Self::with_timeout`].
+    fn guarded<T>(&self, py: Python, f: impl FnOnce() -> T) -> T {
+        match self.timeout {
+            Some(timeout) => with_timeout(py, timeout, f),
+            None => f(),
+        }
+    }
+
+    /// Evaluates the constraints of a solution converted to a NumPy array, see
+    /// [`Self::guarded`].
+    fn guarded_constraints(&self, py: Python, np_solution: &PyAny) -> PyResult<Vec<f64>> {
+        self.guarded(py, || {
+            self.api.constraints(self.inner.as_ref(py), np_solution)
+        })
+    }
+
+    /// Evaluates multiple solutions returned by [`Self::prepare_solution`], using a single
+    /// vectorized call if possible.
+    fn evaluate_prepared_batch(
+        &self,
+        py: Python,
+        solutions: &[Cow<[f64]>],
+    ) -> Vec<(f64, Option<ConstraintViolation>)> {
+        if !self.computes_violation() {
+            let slices: Vec<_> = solutions.iter().map(AsRef::as_ref).collect();
+            if let Some(fitness) = self.evaluate_vectorized(py, &slices) {
+                return fitness.into_iter().map(|fitness| (fitness, None)).collect();
+            }
+        }
+
+        solutions
+            .iter()
+            .map(|solution| self.evaluate_prepared(py, solution))
+            .collect()
+    }
+
+    /// Implements [`Self::evaluate_prepared_batch`], returning the first error instead of
+    /// infinite values.
+    fn try_evaluate_prepared_batch(
+        &self,
+        py: Python,
+        solutions: &[Cow<[f64]>],
+    ) -> PyResult<Vec<(f64, Option<ConstraintViolation>)>> {
+        if !self.computes_violation() {
+            let slices: Vec<_> = solutions.iter().map(AsRef::as_ref).collect();
+            if let Some(fitness) = self.evaluate_vectorized(py, &slices) {
+                return Ok(fitness.into_iter().map(|fitness| (fitness, None)).collect());
+            }
+        }
+
+        solutions
+            .iter()
+            .map(|solution| self.try_evaluate_prepared(py, solution))
+            .collect()
+    }
+
+    /// Evaluates `samples` evenly spaced values of coordinate `dim` across its range,
+    /// holding all other coordinates at `base`, using a single batched evaluation.
+    ///
+    /// Returns `(x, objective)` pairs in increasing order of `x`, with a single sample at
+    /// the center of the range if `samples` is one.
+    /// If `dim` is not a coordinate of `base` within the domain, nothing is evaluated.
+    pub fn sweep_dimension(&self, base: &[f64], dim: usize, samples: usize) -> Vec<(f64, f64)> {
+        let Some(range) = self.domain.get(dim).filter(|_| dim < base.len()) else {
+            warn!(
+                "cannot sweep dimension {dim} of `{}`, which has dimension {}",
+                self.name,
+                self.domain.len().min(base.len())
+            );
+            return Vec::new();
+        };
+
+        let xs: Vec<_> = (0..samples)
+            .map(|i| match samples {
+                1 => (range.start + range.end) / 2.0,
+                _ => range.start + (range.end - range.start) * i as f64 / (samples - 1) as f64,
+            })
+            .collect();
+        let solutions: Vec<_> = xs
+            .iter()
+            .map(|&x| {
+                let mut solution = base.to_vec();
+                solution[dim] = x;
+                solution
+            })
+            .collect();
+
+        xs.into_iter()
+            .zip(self.evaluate_batch(&solutions))
+            .collect()
+    }
+
+    /// Estimates the gradient of the objective at `solution` using finite differences
+    /// with step size `h`.
+    ///
+    /// Central differences are used, except for coordinates where a step would leave the
+    /// domain, which use forward or backward differences instead.
+    /// All perturbed solutions are evaluated in a single batch.
+    ///
+    /// Returns an error if `h` is not positive and finite.
+    pub fn gradient(&self, solution: &[f64], h: f64) -> ExecResult<Vec<f64>> {
+        if !(h > 0.0 && h.is_finite()) {
+            return Err(eyre!(
+                "the step size must be positive and finite, but is {h}"
+            ));
+        }
+
+        enum Difference {
+            Central,
+            Forward,
+            Backward,
+        }
+
+        let differences: Vec<_> = solution
+            .iter()
+            .zip(self.domain.iter())
+            .map(|(&x, range)| {
+                let fits_below = x - h >= range.start;
+                let fits_above = x + h <= range.end;
+                match (fits_below, fits_above) {
+                    (true, false) => Difference::Backward,
+                    (false, true) => Difference::Forward,
+                    _ => Difference::Central,
+                }
+            })
+            .collect();
+
+        let perturbed = |i: usize, step: f64| {
+            let mut solution = solution.to_vec();
+            solution[i] += step;
+            Cow::Owned(self.prepare_solution(&solution).into_owned())
+        };
+
+        // Two solutions per coordinate, where the lower or upper one may be unperturbed.
+        let mut solutions = Vec::with_capacity(2 * solution.len());
+        for (i, difference) in differences.iter().enumerate() {
+            let (lower, upper) = match difference {
+                Difference::Central => (-h, h),
+                Difference::Forward => (0.0, h),
+                Difference::Backward => (-h, 0.0),
+            };
+            solutions.push(perturbed(i, lower));
+            solutions.push(perturbed(i, upper));
+        }
+
+        let fitness: Vec<_> = Python::with_gil(|py| self.evaluate_prepared_batch(py, &solutions))
+            .into_iter()
+            .map(|(fitness, _)| fitness)
+            .collect();
+
+        let gradient = differences
+            .iter()
+            .enumerate()
+            .map(|(i, difference)| {
+                let (lower, upper) = (fitness[2 * i], fitness[2 * i + 1]);
+                match difference {
+                    Difference::Central => (upper - lower) / (2.0 * h),
+                    Difference::Forward | Difference::Backward => (upper - lower) / h,
+                }
+            })
+            .collect();
+        Ok(gradient)
+    }
+
+    /// Evaluates the constraints of the problem for `solution`.
+    ///
+    /// Returns one value per constraint, where positive values indicate a violation
+    /// and values less than or equal to zero mean the constraint is satisfied.
+    /// Problems without constraints return an empty vector.
+    /// The solution is clamped and rounded like for [`Self::evaluate_full`].
+    ///
+    /// If evaluation fails or the solution has the wrong dimension, a single infinite value
+    /// marks the solution as infeasible.
+    pub fn evaluate_constraints(&self, solution: &[f64]) -> Vec<f64> {
+        let Some(solution) = self.prepare_checked(solution) else {
+            return vec![f64::INFINITY];
+        };
+        let result = Python::with_gil(|py| {
+            self.guarded_constraints(py, PyArray1::from_slice(py, &solution))
+        });
+        result.unwrap_or_else(|err| {
+            warn!("constraint evaluation of `{}` failed: {err}", self.name);
+            vec![f64::INFINITY]
+        })
+    }
+
+    /// Returns the inequality constraints in `g(x) <= 0` form as a closure, e.g. for
+    /// nonlinear programming solvers that take separate objective and constraint callbacks,
+    /// see [`Self::as_closure`].
+    ///
+    /// Equality constraints are omitted, and their residuals are available through
+    /// [`Self::evaluate_full`].
+    /// Each call of the closure acquires the GIL, and solutions are clamped and rounded like
+    /// for [`Self::evaluate_full`].
+    /// If evaluation fails or the solution has the wrong dimension, a warning is logged and
+    /// every inequality is [`f64::INFINITY`],
+    /// or a single value is returned if the problem doesn't report its constraint counts.
+    pub fn as_constraint_closure(&self) -> impl Fn(&[f64]) -> Vec<f64> + '_ {
+        |solution| {
+            let Some(solution) = self.prepare_checked(solution) else {
+                return vec![f64::INFINITY; self.num_inequality.max(1)];
+            };
+            let result = Python::with_gil(|py| {
+                self.guarded_constraints(py, PyArray1::from_slice(py, &solution))
+            });
+            match result {
+                Ok(mut constraints) => {
+                    constraints.truncate(constraints.len().saturating_sub(self.num_equality));
+                    constraints
+                }
+                Err(err) => {
+                    warn!("constraint evaluation of `{}` failed: {err}", self.name);
+                    vec![f64::INFINITY; self.num_inequality.max(1)]
+                }
+            }
+        }
+    }
+
+    /// Evaluates the objective value and all constraints of `solution` while holding
+    /// the GIL only once.
+    ///
+    /// If evaluation fails, the objective is [`f64::INFINITY`] and a single infinite
+    /// inequality value marks the solution as infeasible.
+    pub fn evaluate_full(&self, solution: &[f64]) -> Evaluation {
+        let infeasible = Evaluation {
+            objective: f64::INFINITY,
+            inequality: vec![f64::INFINITY],
+            equality: Vec::new(),
+        };
+        let Some(solution) = self.prepare_checked(solution) else {
+            return infeasible;
+        };
+
+        let start = Instant::now();
+        let result = Python::with_gil(|py| {
+            self.guarded(py, || -> PyResult<_> {
+                let np_solution = PyArray1::from_slice(py, &solution);
+                let py_problem = self.inner.as_ref(py);
+                let objective = self.orient(self.api.objective(py_problem, np_solution)?)?;
+                let constraints = self.api.constraints(py_problem, np_solution)?;
+                Ok((objective, constraints))
+            })
+        });
+        self.record_stats(1, start.elapsed());
+
+        match result {
+            Ok((objective, mut inequality)) => {
+                let split = inequality.len().saturating_sub(self.num_equality);
+                let equality = inequality.split_off(split);
+                Evaluation {
+                    objective,
+                    inequality,
+                    equality,
+                }
+            }
+            Err(err) => {
+                warn!("evaluation of `{}` failed: {err}", self.name);
+                infeasible
+            }
+        }
+    }
+
+    /// Returns whether `solution` satisfies all constraints, see [`Evaluation::is_feasible`].
+    ///
+    /// Pass [`Self::equality_tolerance`] as `tol` to be consistent with the recorded violations.
+    pub fn is_feasible(&self, solution: &[f64], tol: f64) -> bool {
+        self.evaluate_full(solution).is_feasible(tol)
+    }
+
+    /// Returns the indices of the inequality constraints that are active at `solution`,
+    /// i.e. whose values are within `tol` of zero.
+    ///
+    /// The values themselves are available from [`Self::evaluate_full`], and equality
+    /// constraints, which are active at every feasible solution, are not included.
+    pub fn active_constraints(&self, solution: &[f64], tol: f64) -> Vec<usize> {
+        self.evaluate_full(solution)
+            .inequality
+            .iter()
+            .enumerate()
+            .filter(|(_, g)| g.abs() <= tol)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Evaluates the [`Feasibility`] of `solution` with a single Python round trip, see
+    /// [`Self::evaluate_full`].
+    ///
+    /// Equality residuals within [`Self::equality_tolerance`] count as satisfied.
+    pub fn evaluate_feasibility(&self, solution: &[f64]) -> Feasibility {
+        let evaluation = self.evaluate_full(solution);
+        let violation = evaluation.violation(self.equality_tolerance);
+        Feasibility {
+            objective: evaluation.objective,
+            feasible: violation.is_feasible(),
+            total_violation: violation.0,
+        }
+    }
 
-            ExecResult::Ok(evaluator)
+    /// Verifies that evaluating the known optimal solution reproduces the known optimum,
+    /// up to a relative tolerance of `1e-3`.
+    ///
+    /// Returns `false` if no reference is available, and an error containing the measured
+    /// and expected values if they disagree, e.g. because enoppy changed a formula.
+    pub fn self_check(&self) -> ExecResult<bool> {
+        const TOLERANCE: f64 = 1e-3;
+
+        let Some((solution, expected)) = &self.reference else {
+            return Ok(false);
+        };
+        let measured = self.evaluate_full(solution).objective;
+        let expected = self.orient(*expected)?;
+
+        if (measured - expected).abs() <= TOLERANCE * expected.abs().max(1.0) {
+            Ok(true)
+        } else {
+            Err(eyre!(
+                "self-check of `{}` failed: evaluating the optimal solution returned {measured}, \
+                but expected {expected}",
+                self.name
+            ))
+        }
+    }
+
+    /// Summarizes the total [`ConstraintViolation`]s of `solutions`, evaluating all
+    /// constraints while holding the GIL once.
+    pub fn violation_summary(&self, solutions: &[Vec<f64>]) -> ViolationSummary {
+        let violations: Vec<_> = Python::with_gil(|_| {
+            solutions
+                .iter()
+                .map(|solution| self.constraint_violation(solution))
+                .collect()
+        });
+        ViolationSummary::from_violations(&violations)
+    }
+
+    /// Evaluates the total [`ConstraintViolation`] of `solution`.
+    ///
+    /// Equality residuals within [`Self::equality_tolerance`] count as satisfied.
+    pub fn constraint_violation(&self, solution: &[f64]) -> ConstraintViolation {
+        ConstraintViolation::with_equality_tolerance(
+            &self.evaluate_constraints(solution),
+            self.num_equality,
+            self.equality_tolerance,
+        )
+    }
+}
+
+// Ensures that the evaluator stays usable from worker threads.
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<EngineeringOptimizationEvaluator>();
+};
+
+impl EngineeringOptimizationEvaluator {
+    /// Evaluates `individuals` like [`Evaluate::evaluate`], but returns the first error
+    /// raised in Python instead of assigning infinite objective values.
+    ///
+    /// If an error is returned, no individual is assigned an objective value and the
+    /// state is unchanged.
+    /// Repaired candidates of [`Self::with_repair`] are still evaluated leniently.
+    pub fn try_evaluate(
+        &mut self,
+        _problem: &EngineeringOptimizationProblem,
+        state: &mut State<EngineeringOptimizationProblem>,
+        individuals: &mut [Individual<EngineeringOptimizationProblem>],
+    ) -> ExecResult<()> {
+        self.evaluate_individuals(state, individuals, |this, solutions| {
+            with_traceback(|py| this.try_evaluate_prepared_batch(py, solutions))
+                .wrap_err_with(|| format!("evaluation of `{}` failed", this.name))
         })
-        .unwrap()
+    }
+
+    /// Implements [`Evaluate::evaluate`] and [`Self::try_evaluate`], where `evaluate_batch`
+    /// evaluates the prepared solutions within the budget.
+    ///
+    /// This is generic over the problem, so wrapping evaluators can reuse it.
+    fn evaluate_individuals<P, E>(
+        &mut self,
+        state: &mut State<P>,
+        individuals: &mut [Individual<P>],
+        evaluate_batch: impl FnOnce(
+            &Self,
+            &[Cow<[f64]>],
+        ) -> Result<Vec<(f64, Option<ConstraintViolation>)>, E>,
+    ) -> Result<(), E>
+    where
+        P: Problem<Encoding = Vec<f64>, Objective = SingleObjective>,
+    {
+        let mut violations = Vec::new();
+
+        let remaining = self.remaining_budget().unwrap_or(usize::MAX);
+        let (within, beyond) = individuals.split_at_mut(remaining.min(individuals.len()));
+
+        let solutions: Vec<_> = within
+            .iter()
+            .map(|individual| self.prepare_solution(individual.solution()))
+            .collect();
+        let mut results = evaluate_batch(self, &solutions)?;
+
+        if let Some(strategy) = self.repair {
+            let mut repaired = vec![false; within.len() + beyond.len()];
+            for ((individual, result), repaired) in
+                within.iter_mut().zip(&mut results).zip(&mut repaired)
+            {
+                if let Some(solution) = self.repair(strategy, individual.solution(), result) {
+                    *individual.solution_mut() = solution;
+                    *repaired = true;
+                }
+            }
+            state.insert(Repaired(repaired));
+        }
+
+        for (individual, (fitness, violation)) in within.iter_mut().zip(results) {
+            if let Some(callback) = &self.callback {
+                callback(individual.solution(), fitness);
+            }
+            if self.track_best {
+                self.record_best(individual.solution(), fitness, violation);
+            }
+            let objective = SingleObjective::try_from(fitness).unwrap_or_default();
+            individual.set_objective(objective);
+            if let (true, Some(violation)) = (self.track_feasibility, violation) {
+                let mut best = state.entry::<BestFeasibility>().or_default();
+                let improves = best.0.is_none_or(|best| objective.value() < best.objective);
+                if improves {
+                    best.0 = Some(Feasibility {
+                        objective: objective.value(),
+                        feasible: violation.is_feasible(),
+                        total_violation: violation.0,
+                    });
+                }
+            }
+            violations.extend(violation);
+        }
+
+        if !beyond.is_empty() {
+            for individual in beyond.iter_mut() {
+                individual.set_objective(SingleObjective::default());
+                violations.push(ConstraintViolation(f64::INFINITY));
+            }
+            state.insert(BudgetExhausted);
+        }
+
+        if self.record_violation {
+            state.insert(ConstraintViolations(violations));
+        }
+        Ok(())
     }
 }
 
@@ -175,22 +2574,147 @@ impl Evaluate for EngineeringOptimizationEvaluator {
     fn evaluate(
         &mut self,
         _problem: &Self::Problem,
-        _state: &mut State<Self::Problem>,
+        state: &mut State<Self::Problem>,
         individuals: &mut [Individual<Self::Problem>],
     ) {
-        Python::with_gil(|py| {
-            for individual in individuals {
-                let solution = Array1::from_vec(individual.solution().clone());
-                let np_solution = solution.into_pyarray(py);
-                let problem = self.inner.as_ref(py);
-                let fitness = problem
-                    .call_method1("evaluate", (np_solution,))
-                    .unwrap()
-                    .extract::<f64>()
-                    .unwrap_or(f64::INFINITY);
-                let objective_value = SingleObjective::try_from(fitness).unwrap_or_default();
-                individual.set_objective(objective_value);
-            }
+        let Ok(()) = self.evaluate_individuals(state, individuals, |this, solutions| {
+            Ok::<_, Infallible>(Python::with_gil(|py| {
+                this.evaluate_prepared_batch(py, solutions)
+            }))
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::mpsc, thread, time::Duration};
+
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    /// A problem built from metadata like [`EngineeringOptimizationProblem::from_spec`], but
+    /// wrapping `None` instead of an enoppy instance.
+    fn cached_problem(py: Python) -> EngineeringOptimizationProblem {
+        EngineeringOptimizationProblem {
+            name: "WeldedBeamDesignProblem".to_string(),
+            module: RWCO_2020.to_string(),
+            dim: 3,
+            domain: fixed_domain().into(),
+            global_optimum: metadata::global_optimum_value("WeldedBeamDesignProblem"),
+            optimal_solution: None,
+            baseline: None,
+            variable_types: vec![VarType::Continuous; 3],
+            variable_names: None,
+            num_inequality: 2,
+            num_equality: 1,
+            equality_tolerance: DEFAULT_EQUALITY_TOLERANCE,
+            minimize: true,
+            objective_scale: Arc::default(),
+            api: PyApi::first_known(),
+            inner: py.None(),
+        }
+    }
+
+    #[test]
+    fn metadata_accessors_and_clone_never_acquire_the_gil() {
+        let problem = Python::with_gil(cached_problem);
+
+        // The GIL is held by this thread while the accessors run on another one, so any
+        // accessor acquiring it would block until the timeout.
+        Python::with_gil(|_| {
+            let (sender, receiver) = mpsc::channel();
+            thread::spawn(move || {
+                let clone = problem.clone();
+                let summary = (
+                    Problem::name(&clone).to_string(),
+                    clone.qualified_name(),
+                    VectorProblem::dimension(&clone),
+                    LimitedVectorProblem::domain(&clone),
+                    clone.lower_bounds(),
+                    clone.upper_bounds(),
+                    clone.midpoint(),
+                    clone.global_optimum_value(),
+                    clone.category(),
+                    clone.num_constraints(),
+                    clone.spec(),
+                    clone.summary(),
+                );
+                // Dropping a handle without the GIL only defers the decrement.
+                drop(clone);
+                sender.send((summary, problem)).unwrap();
+            });
+
+            let (summary, problem) = receiver
+                .recv_timeout(Duration::from_secs(10))
+                .expect("a metadata accessor or clone acquired the GIL");
+            assert_eq!(summary.1, "rwco_2020::WeldedBeamDesignProblem");
+            assert_eq!(summary.2, 3);
+            assert_eq!(summary.9, 3);
+            assert_eq!(summary.10, problem.spec());
         });
     }
+
+    /// A domain whose second variable is fixed.
+    fn fixed_domain() -> Vec<Range<f64>> {
+        vec![-1.0..1.0, 2.5..2.5, 0.0..10.0]
+    }
+
+    #[test]
+    fn from_unit_maps_fixed_variables_to_their_value() {
+        let domain = fixed_domain();
+        for u in [0.0, 0.5, 1.0, f64::INFINITY] {
+            assert_eq!(from_unit(&domain, &[0.5, u, 0.5])[1], 2.5);
+        }
+        assert_eq!(from_unit(&domain, &[0.0, 0.5, 1.0]), vec![-1.0, 2.5, 10.0]);
+    }
+
+    #[test]
+    fn random_in_samples_fixed_variables_as_their_value() {
+        let domain = fixed_domain();
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..100 {
+            let solution = random_in(&domain, &mut rng);
+            assert_eq!(solution[1], 2.5);
+            assert!(domain[0].contains(&solution[0]) && domain[2].contains(&solution[2]));
+        }
+    }
+
+    #[test]
+    fn sampleable_widens_only_fixed_variables() {
+        let domain = sampleable(&fixed_domain());
+        assert_eq!(domain[0], -1.0..1.0);
+        assert_eq!(domain[1], 2.5..2.5f64.next_up());
+        assert_eq!(domain[2], 0.0..10.0);
+
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..100 {
+            assert_eq!(rng.gen_range(domain[1].clone()), 2.5);
+        }
+    }
+
+    #[test]
+    fn clamp_maps_onto_fixed_variables() {
+        let range = 2.5..2.5;
+        for x in [f64::NEG_INFINITY, -3.0, 2.5, 7.0, f64::INFINITY] {
+            assert_eq!(clamp(x, &range), 2.5);
+        }
+        assert_eq!(clamp(11.0, &(0.0..10.0)), 10.0);
+    }
+    #[test]
+    fn from_spec_rejects_malformed_domains() {
+        let spec = |domain: Vec<Range<f64>>| ProblemSpec {
+            name: "WeldedBeamDesignProblem".to_string(),
+            module: RWCO_2020.to_string(),
+            dim: 2,
+            domain,
+        };
+        let err = EngineeringOptimizationProblem::from_spec(spec(vec![0.0..1.0])).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("1 entries, but the dimension is 2"));
+        let err =
+            EngineeringOptimizationProblem::from_spec(spec(vec![0.0..1.0, 2.0..1.0])).unwrap_err();
+        assert!(err.to_string().contains("`domain[1]`"));
+    }
 }
@@ -0,0 +1,166 @@
+//! Hardcoded metadata for the RWCO 2020 problems, keyed by enoppy class name.
+//!
+//! The values are taken from Kumar et al. (2020), "A test-suite of non-convex constrained
+//! optimization problems from the real-world and some baseline results".
+
+use crate::{ProblemCategory, VarType};
+
+/// Returns the best-known objective value of the problem `name`.
+pub(crate) fn global_optimum_value(name: &str) -> Option<f64> {
+    let value = match name {
+        "HeatExchangerNetworkDesignCase1Problem" => 1.8931162966e+02,
+        "HeatExchangerNetworkDesignCase2Problem" => 7.0490369540e+03,
+        "OptimalOperationAlkylationUnitProblem" => -4.5291197395e+03,
+        "ReactorNetworkDesignProblem" => -3.8826043623e-01,
+        "HaverlyPoolingProblem" => -4.0005600000e+02,
+        "BlendingPoolingSeparationProblem" => 1.8638304088e+00,
+        "PropaneIsobutaneNButaneNonsharpSeparationProblem" => 1.5670451000e+00,
+        "ProcessSynthesis01Problem" => 2.0000000000e+00,
+        "ProcessSynthesisAndDesignProblem" => 2.5576545740e+00,
+        "ProcessFlowSheetingProblem" => 1.0765430833e+00,
+        "TwoReactorProblem" => 9.9238463653e+01,
+        "ProcessSynthesis02Problem" => 2.9248305537e+00,
+        "ProcessDesignProblem" => 2.6887000000e+04,
+        "MultiProductBatchPlantProblem" => 5.3638942722e+04,
+        "WeightMinimizationSpeedReducerProblem" => 2.9944244658e+03,
+        "OptimalDesignIndustrialRefrigerationSystemProblem" => 3.2213000814e-02,
+        "TensionCompressionSpringDesignProblem" => 1.2665232788e-02,
+        "PressureVesselDesignProblem" => 5.8853327736e+03,
+        "WeldedBeamDesignProblem" => 1.6702177263e+00,
+        "ThreeBarTrussDesignProblem" => 2.6389584338e+02,
+        "MultipleDiskClutchBrakeDesignProblem" => 2.3524245790e-01,
+        "PlanetaryGearTrainDesignOptimizationProblem" => 5.2576870748e-01,
+        "StepConePulleyProblem" => 1.6069868725e+01,
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Returns the best-known solution of the problem `name`.
+///
+/// Only the classic mechanical design problems are covered.
+pub(crate) fn optimal_solution(name: &str) -> Option<Vec<f64>> {
+    let solution = match name {
+        "WeightMinimizationSpeedReducerProblem" => vec![
+            3.5,
+            0.7,
+            17.0,
+            7.3,
+            7.715_319_91,
+            3.350_214_67,
+            5.286_654_46,
+        ],
+        "TensionCompressionSpringDesignProblem" => vec![0.051_689_061, 0.356_717_736, 11.288_966],
+        "PressureVesselDesignProblem" => vec![0.778_168_6, 0.384_649_2, 40.319_618_7, 200.0],
+        "WeldedBeamDesignProblem" => vec![
+            0.198_832_307_22,
+            3.337_365_298_65,
+            9.192_024_322_48,
+            0.198_832_307_22,
+        ],
+        "ThreeBarTrussDesignProblem" => vec![0.788_675_13, 0.408_248_28],
+        _ => return None,
+    };
+    Some(solution)
+}
+
+/// Returns the names of the decision variables of the problem `name`.
+///
+/// Only the classic mechanical design problems are covered.
+pub(crate) fn variable_names(name: &str) -> Option<Vec<String>> {
+    let names: &[&str] = match name {
+        "WeightMinimizationSpeedReducerProblem" => &["b", "m", "z", "l1", "l2", "d1", "d2"],
+        "TensionCompressionSpringDesignProblem" => &["d", "D", "N"],
+        "PressureVesselDesignProblem" => &["Ts", "Th", "R", "L"],
+        "WeldedBeamDesignProblem" => &["h", "l", "t", "b"],
+        "ThreeBarTrussDesignProblem" => &["A1", "A2"],
+        _ => return None,
+    };
+    Some(names.iter().map(|name| name.to_string()).collect())
+}
+
+/// Returns the variable types of the problem `name`, if it has non-continuous variables.
+///
+/// Index-coded discrete variables (e.g. the module choices of the planetary gear train)
+/// are reported as [`VarType::Integer`], as the index is what is encoded in the solution.
+pub(crate) fn variable_types(name: &str) -> Option<Vec<VarType>> {
+    let types = match name {
+        "MultipleDiskClutchBrakeDesignProblem" => vec![VarType::Integer; 5],
+        "PlanetaryGearTrainDesignOptimizationProblem" => vec![VarType::Integer; 9],
+        _ => return None,
+    };
+    Some(types)
+}
+
+/// Returns the category of the problem `name`.
+///
+/// This follows the grouping of the suite, except that the welded beam and three-bar truss,
+/// which the suite lists under mechanical design, are categorized as structural design.
+pub(crate) fn category(name: &str) -> Option<ProblemCategory> {
+    let category = match name {
+        "HeatExchangerNetworkDesignCase1Problem"
+        | "HeatExchangerNetworkDesignCase2Problem"
+        | "OptimalOperationAlkylationUnitProblem"
+        | "ReactorNetworkDesignProblem"
+        | "HaverlyPoolingProblem"
+        | "BlendingPoolingSeparationProblem"
+        | "PropaneIsobutaneNButaneNonsharpSeparationProblem" => ProblemCategory::ChemicalProcess,
+        "ProcessSynthesis01Problem"
+        | "ProcessSynthesisAndDesignProblem"
+        | "ProcessFlowSheetingProblem"
+        | "TwoReactorProblem"
+        | "ProcessSynthesis02Problem"
+        | "ProcessDesignProblem"
+        | "MultiProductBatchPlantProblem" => ProblemCategory::ProcessSynthesis,
+        "WeightMinimizationSpeedReducerProblem"
+        | "OptimalDesignIndustrialRefrigerationSystemProblem"
+        | "TensionCompressionSpringDesignProblem"
+        | "PressureVesselDesignProblem"
+        | "MultipleDiskClutchBrakeDesignProblem"
+        | "PlanetaryGearTrainDesignOptimizationProblem"
+        | "StepConePulleyProblem" => ProblemCategory::MechanicalDesign,
+        "WeldedBeamDesignProblem" | "ThreeBarTrussDesignProblem" => {
+            ProblemCategory::StructuralDesign
+        }
+        _ => return None,
+    };
+    Some(category)
+}
+
+/// The citation of the RWCO 2020 suite.
+const SUITE_REFERENCE: &str = "A. Kumar, G. Wu, M. Z. Ali, R. Mallipeddi, P. N. Suganthan, \
+    and S. Das (2020). A test-suite of non-convex constrained optimization problems from the \
+    real-world and some baseline results. Swarm and Evolutionary Computation, 56, 100693.";
+
+/// Returns the citation of the problem `name`, which is the original source for the
+/// classic problems and the suite otherwise.
+pub(crate) fn reference(name: &str) -> Option<&'static str> {
+    let reference = match name {
+        "WeightMinimizationSpeedReducerProblem" => {
+            "J. Golinski (1973). An adaptive optimization system applied to machine synthesis. \
+            Mechanism and Machine Theory, 8(4), 419-436."
+        }
+        "TensionCompressionSpringDesignProblem" => {
+            "J. S. Arora (1989). Introduction to Optimum Design. McGraw-Hill."
+        }
+        "PressureVesselDesignProblem" => {
+            "B. K. Kannan and S. N. Kramer (1994). An augmented Lagrange multiplier based method \
+            for mixed integer discrete continuous optimization and its applications to \
+            mechanical design. Journal of Mechanical Design, 116(2), 405-411."
+        }
+        "WeldedBeamDesignProblem" => {
+            "K. M. Ragsdell and D. T. Phillips (1976). Optimal design of a class of welded \
+            structures using geometric programming. Journal of Engineering for Industry, \
+            98(3), 1021-1025."
+        }
+        "HaverlyPoolingProblem" => {
+            "C. A. Haverly (1978). Studies of the behavior of recursion for the pooling \
+            problem. ACM SIGMAP Bulletin, 25, 19-28."
+        }
+        _ => {
+            category(name)?;
+            SUITE_REFERENCE
+        }
+    };
+    Some(reference)
+}
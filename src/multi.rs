@@ -0,0 +1,191 @@
+//! Multi-objective enoppy problems.
+
+use std::{ops::Range, sync::Arc};
+
+use eyre::WrapErr;
+use log::warn;
+use mahf::{
+    problems::{Evaluate, LimitedVectorProblem, VectorProblem},
+    ExecResult, Individual, MultiObjective, Problem, State,
+};
+use numpy::PyArray1;
+use pyo3::{IntoPy, PyObject, PyResult, Python};
+
+use crate::{
+    compat::{with_traceback, PyApi},
+    py_count, py_is_minimization, sampleable,
+};
+
+const MOEOSMA_2023: &str = "enoppy.paper_based.moeosma_2023";
+
+/// A multi-objective enoppy problem, e.g. from the MOEOSMA 2023 suite.
+///
+/// Objectives of maximization problems are negated, like for
+/// [`EngineeringOptimizationProblem`](crate::EngineeringOptimizationProblem).
+#[derive(Debug)]
+pub struct MultiObjectiveEngineeringProblem {
+    name: String,
+    module: String,
+    dim: usize,
+    domain: Arc<[Range<f64>]>,
+    num_objectives: Option<usize>,
+    minimize: bool,
+    api: PyApi,
+    inner: PyObject,
+}
+
+impl MultiObjectiveEngineeringProblem {
+    /// Loads the MOEOSMA 2023 problem `name`.
+    pub fn new(name: impl AsRef<str>) -> ExecResult<Self> {
+        Self::from_module(MOEOSMA_2023, name.as_ref())
+    }
+
+    /// Loads the multi-objective problem `class` from an arbitrary enoppy `module`.
+    pub fn from_module(module: &str, class: &str) -> ExecResult<Self> {
+        with_traceback(|py| -> PyResult<Self> {
+            let py_problem = py.import(module)?.getattr(class)?.call0()?;
+            let api = PyApi::resolve(py_problem)?;
+            let dim = api.dimension(py_problem)?;
+
+            Ok(Self {
+                name: class.to_string(),
+                module: module.to_string(),
+                dim,
+                domain: api.domain(py_problem, dim)?.into(),
+                num_objectives: py_count(py_problem, &["n_objs", "n_obj"]),
+                minimize: py_is_minimization(py_problem),
+                api,
+                inner: py_problem.into_py(py),
+            })
+        })
+        .wrap_err_with(|| format!("failed to load problem `{class}` from `{module}`"))
+    }
+
+    pub fn module(&self) -> &str {
+        &self.module
+    }
+
+    /// Returns the number of objectives, if the Python problem reports it.
+    pub fn num_objectives(&self) -> Option<usize> {
+        self.num_objectives
+    }
+
+    pub fn evaluator(&self) -> MultiObjectiveEngineeringEvaluator {
+        MultiObjectiveEngineeringEvaluator::new(self)
+    }
+}
+
+impl Problem for MultiObjectiveEngineeringProblem {
+    type Encoding = Vec<f64>;
+    type Objective = MultiObjective;
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+}
+
+impl VectorProblem for MultiObjectiveEngineeringProblem {
+    type Element = f64;
+
+    fn dimension(&self) -> usize {
+        self.dim
+    }
+}
+
+/// Degenerate ranges are widened like for
+/// [`EngineeringOptimizationProblem`](crate::EngineeringOptimizationProblem)s.
+impl LimitedVectorProblem for MultiObjectiveEngineeringProblem {
+    fn domain(&self) -> Vec<Range<Self::Element>> {
+        sampleable(&self.domain)
+    }
+}
+
+/// Evaluator for [`MultiObjectiveEngineeringProblem`]s.
+///
+/// Solutions that fail to evaluate receive infinite values for all objectives.
+#[derive(Clone)]
+pub struct MultiObjectiveEngineeringEvaluator {
+    name: String,
+    api: PyApi,
+    inner: PyObject,
+    dim: usize,
+    num_objectives: Option<usize>,
+    minimize: bool,
+}
+
+impl MultiObjectiveEngineeringEvaluator {
+    pub fn new(problem: &MultiObjectiveEngineeringProblem) -> Self {
+        Python::with_gil(|py| Self {
+            name: problem.name.clone(),
+            api: problem.api,
+            inner: problem.inner.clone_ref(py),
+            dim: problem.dim,
+            num_objectives: problem.num_objectives,
+            minimize: problem.minimize,
+        })
+    }
+
+    /// Evaluates the objective values of a single `solution`.
+    ///
+    /// Errors during evaluation, including solutions with the wrong dimension,
+    /// result in an empty vector.
+    pub fn evaluate_one(&self, solution: &[f64]) -> Vec<f64> {
+        if solution.len() != self.dim {
+            warn!(
+                "skipping evaluation of `{}`: solution has dimension {}, but expected {}",
+                self.name,
+                solution.len(),
+                self.dim
+            );
+            return Vec::new();
+        }
+
+        let result = Python::with_gil(|py| {
+            let np_solution = PyArray1::from_slice(py, solution);
+            self.api.objectives(self.inner.as_ref(py), np_solution)
+        });
+        match result {
+            Ok(objectives) if self.minimize => objectives,
+            Ok(objectives) => objectives.into_iter().map(|f| -f).collect(),
+            Err(err) => {
+                warn!("evaluation of `{}` failed: {err}", self.name);
+                Vec::new()
+            }
+        }
+    }
+
+    /// Returns the objective assigned to solutions that fail to evaluate.
+    fn worst(&self, num_objectives: usize) -> MultiObjective {
+        MultiObjective::try_from(vec![f64::INFINITY; num_objectives]).unwrap()
+    }
+}
+
+impl Evaluate for MultiObjectiveEngineeringEvaluator {
+    type Problem = MultiObjectiveEngineeringProblem;
+
+    fn evaluate(
+        &mut self,
+        _problem: &Self::Problem,
+        _state: &mut State<Self::Problem>,
+        individuals: &mut [Individual<Self::Problem>],
+    ) {
+        let objectives: Vec<_> = individuals
+            .iter()
+            .map(|individual| self.evaluate_one(individual.solution()))
+            .collect();
+
+        // Failed evaluations get as many objectives as the successful ones.
+        let num_objectives = self
+            .num_objectives
+            .or_else(|| objectives.iter().map(Vec::len).find(|&n| n > 0))
+            .unwrap_or(1);
+
+        for (individual, objectives) in individuals.iter_mut().zip(objectives) {
+            let objective = MultiObjective::try_from(objectives)
+                .ok()
+                .filter(|objective| objective.value().len() == num_objectives)
+                .unwrap_or_else(|| self.worst(num_objectives));
+            individual.set_objective(objective);
+        }
+    }
+}
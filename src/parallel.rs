@@ -0,0 +1,180 @@
+//! Parallel evaluation using a pool of Python worker processes.
+
+use std::{borrow::Cow, convert::Infallible, ops::Range, time::Instant};
+
+use eyre::WrapErr;
+use log::warn;
+use mahf::{problems::Evaluate, ExecResult, Individual, State};
+use numpy::PyArray1;
+use pyo3::{types::PyList, PyAny, PyObject, PyResult, Python};
+
+use crate::{
+    compat::extract_fitness, ConstraintViolation, DynEngineeringProblem,
+    EngineeringOptimizationEvaluator, EngineeringOptimizationProblem, EvaluationStats,
+};
+
+/// An evaluator that splits each batch across a pool of Python worker processes.
+///
+/// Every worker runs in its own interpreter, so pure-Python problems are evaluated
+/// without contending for the GIL.
+/// The pool is created with the `spawn` start method of `multiprocessing`, which
+/// requires `sys.executable` to point to a Python interpreter with enoppy installed.
+///
+/// # Overhead
+///
+/// Each batch is split into one chunk per worker, and every chunk pickles a copy of the
+/// problem instance along with its solutions.
+/// This costs on the order of a millisecond per chunk, so the speedup approaches the
+/// number of workers only for problems whose evaluation is considerably more expensive,
+/// e.g. the chemical process problems.
+/// For cheap problems, [`EngineeringOptimizationEvaluator`] is faster.
+///
+/// Since workers evaluate copies, problems with internal state don't observe these
+/// evaluations, and only objective values are computed, i.e. constraint violations
+/// are neither recorded nor penalized.
+pub struct ParallelEvaluator {
+    inner: EngineeringOptimizationEvaluator,
+    pool: PyObject,
+    n_workers: usize,
+}
+
+impl ParallelEvaluator {
+    /// Starts a pool of `n_workers` processes evaluating `problem`.
+    pub fn new(problem: &EngineeringOptimizationProblem, n_workers: usize) -> ExecResult<Self> {
+        let n_workers = n_workers.max(1);
+        let pool = Python::with_gil(|py| -> PyResult<_> {
+            let context = py
+                .import("multiprocessing")?
+                .call_method1("get_context", ("spawn",))?;
+            Ok(context.call_method1("Pool", (n_workers,))?.into())
+        })
+        .wrap_err_with(|| format!("failed to start {n_workers} Python worker processes"))?;
+
+        Ok(Self {
+            inner: problem.evaluator(),
+            pool,
+            n_workers,
+        })
+    }
+
+    pub fn n_workers(&self) -> usize {
+        self.n_workers
+    }
+
+    /// Returns the evaluation statistics, where the time includes the serialization overhead.
+    pub fn stats(&self) -> EvaluationStats {
+        self.inner.stats()
+    }
+
+    /// Evaluates `solutions` in the worker processes, returning the raw fitness values.
+    fn evaluate_parallel(
+        inner: &EngineeringOptimizationEvaluator,
+        pool: &PyObject,
+        n_workers: usize,
+        py: Python,
+        solutions: &[Cow<[f64]>],
+    ) -> PyResult<Vec<f64>> {
+        let chunk_size = solutions.len().div_ceil(n_workers);
+        let arrays = PyList::new(
+            py,
+            solutions
+                .iter()
+                .map(|solution| PyArray1::from_slice(py, solution)),
+        );
+        let method = inner.api.fitness_method(inner.inner.as_ref(py))?;
+        pool.as_ref(py)
+            .call_method1("map", (method, arrays, chunk_size))?
+            .extract::<Vec<&PyAny>>()?
+            .into_iter()
+            .map(extract_fitness)
+            .collect()
+    }
+
+    /// Evaluates the prepared `solutions` of a batch, see [`Evaluate::evaluate`].
+    fn evaluate_batch(
+        inner: &EngineeringOptimizationEvaluator,
+        pool: &PyObject,
+        n_workers: usize,
+        py: Python,
+        solutions: &[Cow<[f64]>],
+    ) -> Vec<(f64, Option<ConstraintViolation>)> {
+        // Solutions with the wrong dimension are evaluated in-process, which rejects them,
+        // and so are constraints, as the workers only return the fitness.
+        let dim = inner.domain.len();
+        if inner.computes_violation() || solutions.iter().any(|solution| solution.len() != dim) {
+            return inner.evaluate_prepared_batch(py, solutions);
+        }
+
+        let count = solutions.len();
+        if count == 0 {
+            return Vec::new();
+        }
+        let start = Instant::now();
+        let fitness = Self::evaluate_parallel(inner, pool, n_workers, py, solutions)
+            .unwrap_or_else(|err| {
+                warn!("parallel evaluation of `{}` failed: {err}", inner.name);
+                vec![f64::INFINITY; count]
+            });
+        inner.record_stats(count, start.elapsed());
+
+        fitness
+            .into_iter()
+            .map(|fitness| {
+                let fitness = inner
+                    .orient(fitness)
+                    .unwrap_or_else(|err| inner.failed(err).0);
+                (fitness, None)
+            })
+            .collect()
+    }
+}
+
+impl Drop for ParallelEvaluator {
+    fn drop(&mut self) {
+        Python::with_gil(|py| {
+            if let Err(err) = self.pool.as_ref(py).call_method0("terminate") {
+                warn!("failed to terminate Python worker processes: {err}");
+            }
+        });
+    }
+}
+
+impl Evaluate for ParallelEvaluator {
+    type Problem = EngineeringOptimizationProblem;
+
+    fn evaluate(
+        &mut self,
+        _problem: &Self::Problem,
+        state: &mut State<Self::Problem>,
+        individuals: &mut [Individual<Self::Problem>],
+    ) {
+        let (pool, n_workers) = (&self.pool, self.n_workers);
+        let Ok(()) = self
+            .inner
+            .evaluate_individuals(state, individuals, |inner, solutions| {
+                Ok::<_, Infallible>(Python::with_gil(|py| {
+                    Self::evaluate_batch(inner, pool, n_workers, py, solutions)
+                }))
+            });
+    }
+}
+
+impl DynEngineeringProblem for ParallelEvaluator {
+    fn name(&self) -> &str {
+        &self.inner.name
+    }
+
+    fn dimension(&self) -> usize {
+        self.inner.domain.len()
+    }
+
+    fn domain(&self) -> Vec<Range<f64>> {
+        self.inner.domain.to_vec()
+    }
+
+    /// Evaluates `solution` in this process, as a single solution can't be split across
+    /// the workers.
+    fn evaluate_one(&self, solution: &[f64]) -> f64 {
+        self.inner.evaluate_one(solution)
+    }
+}
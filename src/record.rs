@@ -0,0 +1,73 @@
+//! Serializable evaluation results.
+
+use std::{fmt::Write as _, fs::OpenOptions, io::Write as _, path::Path};
+
+use eyre::WrapErr;
+use mahf::ExecResult;
+use serde::{Deserialize, Serialize};
+
+use crate::EngineeringOptimizationEvaluator;
+
+/// The result of evaluating a single solution, see
+/// [`EngineeringOptimizationEvaluator::evaluate_to_record`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub solution: Vec<f64>,
+    /// The objective value, negated for maximization problems.
+    pub objective: f64,
+    /// The total [`ConstraintViolation`](crate::ConstraintViolation).
+    pub violation: f64,
+    pub feasible: bool,
+}
+
+impl EngineeringOptimizationEvaluator {
+    /// Evaluates `solution` into a [`Record`], see [`Self::evaluate_feasibility`].
+    pub fn evaluate_to_record(&self, solution: &[f64]) -> Record {
+        let feasibility = self.evaluate_feasibility(solution);
+        Record {
+            solution: solution.to_vec(),
+            objective: feasibility.objective,
+            violation: feasibility.total_violation,
+            feasible: feasibility.feasible,
+        }
+    }
+}
+
+/// Appends `records` to the CSV file at `path`, creating it if it doesn't exist.
+///
+/// Each row contains the objective, violation, and feasibility, followed by one column per
+/// coordinate of the solution.
+/// A header with the columns `objective,violation,feasible,x0,x1,...` is written if the file
+/// is empty, where the number of coordinates is taken from the first record.
+pub fn append_records_to_csv(path: impl AsRef<Path>, records: &[Record]) -> ExecResult<()> {
+    let path = path.as_ref();
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .wrap_err_with(|| format!("failed to open `{}`", path.display()))?;
+
+    let mut content = String::new();
+    let is_empty = file.metadata().map(|metadata| metadata.len() == 0)?;
+    if let (true, Some(first)) = (is_empty, records.first()) {
+        content.push_str("objective,violation,feasible");
+        for i in 0..first.solution.len() {
+            write!(content, ",x{i}")?;
+        }
+        content.push('\n');
+    }
+    for record in records {
+        write!(
+            content,
+            "{},{},{}",
+            record.objective, record.violation, record.feasible
+        )?;
+        for x in &record.solution {
+            write!(content, ",{x}")?;
+        }
+        content.push('\n');
+    }
+
+    file.write_all(content.as_bytes())
+        .wrap_err_with(|| format!("failed to write `{}`", path.display()))
+}
@@ -0,0 +1,86 @@
+//! Repairing infeasible solutions during evaluation.
+
+use better_any::{Tid, TidAble};
+use mahf::CustomState;
+use pyo3::Python;
+
+use crate::{clamp, ConstraintViolation, EngineeringOptimizationEvaluator};
+
+/// How [`EngineeringOptimizationEvaluator::with_repair`] repairs infeasible solutions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RepairStrategy {
+    /// Clamps the solution into the domain.
+    ///
+    /// Solutions within the domain are not changed, even if they violate other constraints.
+    Clamp,
+    /// Clamps the solution into the domain like [`RepairStrategy::Clamp`], and then moves it
+    /// halfway toward the center of the domain until it is feasible, evaluating at most
+    /// `max_steps` candidates in total.
+    TowardCenter { max_steps: usize },
+}
+
+/// Whether each individual of the last evaluated batch was repaired, in the same order
+/// as the individuals.
+///
+/// This state is inserted by [`EngineeringOptimizationEvaluator`] if enabled
+/// with [`EngineeringOptimizationEvaluator::with_repair`].
+#[derive(Clone, Debug, Default, Tid)]
+pub struct Repaired(pub Vec<bool>);
+
+impl CustomState<'_> for Repaired {}
+
+impl EngineeringOptimizationEvaluator {
+    /// Repairs `solution` with `strategy` if its `result` is infeasible, re-evaluating each
+    /// repaired candidate and updating `result`.
+    ///
+    /// Stops once the [evaluation budget](Self::with_evaluation_budget) is exhausted, and
+    /// returns the last candidate if any was evaluated.
+    pub(crate) fn repair(
+        &self,
+        strategy: RepairStrategy,
+        solution: &[f64],
+        result: &mut (f64, Option<ConstraintViolation>),
+    ) -> Option<Vec<f64>> {
+        let is_feasible = |result: &(f64, Option<ConstraintViolation>)| {
+            result.1.is_none_or(|violation| violation.is_feasible())
+        };
+        if is_feasible(result) || solution.len() != self.domain.len() {
+            return None;
+        }
+
+        let max_steps = match strategy {
+            RepairStrategy::Clamp => 1,
+            RepairStrategy::TowardCenter { max_steps } => max_steps,
+        };
+        let mut candidate: Vec<_> = solution
+            .iter()
+            .zip(self.domain.iter())
+            .map(|(&x, range)| clamp(x, range))
+            .collect();
+        let clamped = candidate != solution;
+        if !clamped && strategy == RepairStrategy::Clamp {
+            return None;
+        }
+
+        let mut evaluated = 0;
+        Python::with_gil(|py| {
+            for step in 0..max_steps {
+                if self.remaining_budget() == Some(0) {
+                    break;
+                }
+                if step > 0 || !clamped {
+                    for (x, range) in candidate.iter_mut().zip(self.domain.iter()) {
+                        *x = (*x + (range.start + range.end) / 2.0) / 2.0;
+                    }
+                }
+                *result = self.evaluate_prepared(py, &self.prepare_solution(&candidate));
+                evaluated += 1;
+                if is_feasible(result) {
+                    break;
+                }
+            }
+        });
+
+        (evaluated > 0).then_some(candidate)
+    }
+}
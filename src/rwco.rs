@@ -0,0 +1,116 @@
+//! The problems of the RWCO 2020 suite.
+
+use mahf::ExecResult;
+
+use crate::{metadata, EngineeringOptimizationProblem, ProblemCategory};
+
+/// A problem of the RWCO 2020 suite.
+///
+/// With the `strum` feature, the variants can also be iterated with `strum::IntoEnumIterator`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "strum", derive(strum::EnumIter))]
+pub enum RwcoProblem {
+    HeatExchangerNetworkDesignCase1,
+    HeatExchangerNetworkDesignCase2,
+    HaverlyPooling,
+    BlendingPoolingSeparation,
+    PropaneIsobutaneNButaneNonsharpSeparation,
+    OptimalOperationAlkylationUnit,
+    ReactorNetworkDesign,
+    ProcessSynthesis01,
+    ProcessSynthesis02,
+    ProcessDesign,
+    ProcessSynthesisAndDesign,
+    ProcessFlowSheeting,
+    TwoReactor,
+    MultiProductBatchPlant,
+    WeightMinimizationSpeedReducer,
+    OptimalDesignIndustrialRefrigerationSystem,
+    TensionCompressionSpringDesign,
+    PressureVesselDesign,
+    WeldedBeamDesign,
+    ThreeBarTrussDesign,
+    MultipleDiskClutchBrakeDesign,
+    PlanetaryGearTrainDesignOptimization,
+    StepConePulley,
+}
+
+impl RwcoProblem {
+    /// All problems of the suite.
+    pub const ALL: [RwcoProblem; 23] = [
+        RwcoProblem::HeatExchangerNetworkDesignCase1,
+        RwcoProblem::HeatExchangerNetworkDesignCase2,
+        RwcoProblem::HaverlyPooling,
+        RwcoProblem::BlendingPoolingSeparation,
+        RwcoProblem::PropaneIsobutaneNButaneNonsharpSeparation,
+        RwcoProblem::OptimalOperationAlkylationUnit,
+        RwcoProblem::ReactorNetworkDesign,
+        RwcoProblem::ProcessSynthesis01,
+        RwcoProblem::ProcessSynthesis02,
+        RwcoProblem::ProcessDesign,
+        RwcoProblem::ProcessSynthesisAndDesign,
+        RwcoProblem::ProcessFlowSheeting,
+        RwcoProblem::TwoReactor,
+        RwcoProblem::MultiProductBatchPlant,
+        RwcoProblem::WeightMinimizationSpeedReducer,
+        RwcoProblem::OptimalDesignIndustrialRefrigerationSystem,
+        RwcoProblem::TensionCompressionSpringDesign,
+        RwcoProblem::PressureVesselDesign,
+        RwcoProblem::WeldedBeamDesign,
+        RwcoProblem::ThreeBarTrussDesign,
+        RwcoProblem::MultipleDiskClutchBrakeDesign,
+        RwcoProblem::PlanetaryGearTrainDesignOptimization,
+        RwcoProblem::StepConePulley,
+    ];
+
+    /// Returns the name of the enoppy class implementing the problem.
+    pub fn class_name(self) -> &'static str {
+        match self {
+            RwcoProblem::HeatExchangerNetworkDesignCase1 => {
+                "HeatExchangerNetworkDesignCase1Problem"
+            }
+            RwcoProblem::HeatExchangerNetworkDesignCase2 => {
+                "HeatExchangerNetworkDesignCase2Problem"
+            }
+            RwcoProblem::HaverlyPooling => "HaverlyPoolingProblem",
+            RwcoProblem::BlendingPoolingSeparation => "BlendingPoolingSeparationProblem",
+            RwcoProblem::PropaneIsobutaneNButaneNonsharpSeparation => {
+                "PropaneIsobutaneNButaneNonsharpSeparationProblem"
+            }
+            RwcoProblem::OptimalOperationAlkylationUnit => "OptimalOperationAlkylationUnitProblem",
+            RwcoProblem::ReactorNetworkDesign => "ReactorNetworkDesignProblem",
+            RwcoProblem::ProcessSynthesis01 => "ProcessSynthesis01Problem",
+            RwcoProblem::ProcessSynthesis02 => "ProcessSynthesis02Problem",
+            RwcoProblem::ProcessDesign => "ProcessDesignProblem",
+            RwcoProblem::ProcessSynthesisAndDesign => "ProcessSynthesisAndDesignProblem",
+            RwcoProblem::ProcessFlowSheeting => "ProcessFlowSheetingProblem",
+            RwcoProblem::TwoReactor => "TwoReactorProblem",
+            RwcoProblem::MultiProductBatchPlant => "MultiProductBatchPlantProblem",
+            RwcoProblem::WeightMinimizationSpeedReducer => "WeightMinimizationSpeedReducerProblem",
+            RwcoProblem::OptimalDesignIndustrialRefrigerationSystem => {
+                "OptimalDesignIndustrialRefrigerationSystemProblem"
+            }
+            RwcoProblem::TensionCompressionSpringDesign => "TensionCompressionSpringDesignProblem",
+            RwcoProblem::PressureVesselDesign => "PressureVesselDesignProblem",
+            RwcoProblem::WeldedBeamDesign => "WeldedBeamDesignProblem",
+            RwcoProblem::ThreeBarTrussDesign => "ThreeBarTrussDesignProblem",
+            RwcoProblem::MultipleDiskClutchBrakeDesign => "MultipleDiskClutchBrakeDesignProblem",
+            RwcoProblem::PlanetaryGearTrainDesignOptimization => {
+                "PlanetaryGearTrainDesignOptimizationProblem"
+            }
+            RwcoProblem::StepConePulley => "StepConePulleyProblem",
+        }
+    }
+
+    pub fn category(self) -> ProblemCategory {
+        metadata::category(self.class_name()).unwrap()
+    }
+
+    pub fn try_build(self) -> ExecResult<EngineeringOptimizationProblem> {
+        EngineeringOptimizationProblem::new(self.class_name())
+    }
+
+    pub fn build(self) -> EngineeringOptimizationProblem {
+        self.try_build().unwrap()
+    }
+}
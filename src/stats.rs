@@ -0,0 +1,31 @@
+//! Statistics about the evaluations performed by an evaluator.
+
+use std::time::Duration;
+
+/// Accumulated statistics about the evaluations performed through Python.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct EvaluationStats {
+    /// The number of evaluated solutions.
+    pub count: usize,
+    /// The number of Python calls, which is lower than `count` for vectorized evaluations.
+    pub calls: usize,
+    /// The total wall-clock time spent in Python.
+    pub total_python_time: Duration,
+}
+
+impl EvaluationStats {
+    /// Returns the mean time spent in Python per evaluated solution.
+    pub fn mean_time(&self) -> Duration {
+        if self.count == 0 {
+            Duration::ZERO
+        } else {
+            self.total_python_time.div_f64(self.count as f64)
+        }
+    }
+
+    pub(crate) fn record(&mut self, count: usize, elapsed: Duration) {
+        self.count += count;
+        self.calls += 1;
+        self.total_python_time += elapsed;
+    }
+}
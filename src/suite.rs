@@ -0,0 +1,95 @@
+//! Running mahf experiments on the whole RWCO 2020 suite.
+//!
+//! mahf's [`par_experiment`] runs a [`Configuration`] on a slice of problems, and expects
+//! the evaluator to be inserted into the [`State`] by a setup function that doesn't know
+//! which problem is being optimized.
+//! [`SuiteEvaluator`] bridges this by evaluating with whatever problem mahf passes to it,
+//! so the same setup can be shared by all problems, see [`par_experiment_rwco`].
+//!
+//! Note that all runs share the GIL, so Python evaluations are serialized even though
+//! mahf executes the runs in parallel.
+
+use std::path::Path;
+
+use mahf::{
+    experiments::par_experiment, problems::Evaluate, Configuration, ExecResult, Individual, State,
+};
+
+use crate::{EngineeringOptimizationEvaluator, EngineeringOptimizationProblem};
+
+/// Loads all available problems, each paired with a default evaluator.
+///
+/// Problems that fail to load are skipped, see [`EngineeringOptimizationProblem::all`].
+pub fn rwco_suite() -> ExecResult<
+    Vec<(
+        EngineeringOptimizationProblem,
+        EngineeringOptimizationEvaluator,
+    )>,
+> {
+    let problems = EngineeringOptimizationProblem::all()?;
+    Ok(problems
+        .into_iter()
+        .map(|problem| {
+            let evaluator = problem.evaluator();
+            (problem, evaluator)
+        })
+        .collect())
+}
+
+/// An evaluator that evaluates individuals on the problem passed by mahf, instead of
+/// being bound to a single problem like [`EngineeringOptimizationEvaluator`].
+///
+/// The underlying evaluator is created on first use and recreated whenever the problem
+/// changes, using the default configuration.
+#[derive(Clone, Default)]
+pub struct SuiteEvaluator {
+    inner: Option<EngineeringOptimizationEvaluator>,
+}
+
+impl SuiteEvaluator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Evaluate for SuiteEvaluator {
+    type Problem = EngineeringOptimizationProblem;
+
+    fn evaluate(
+        &mut self,
+        problem: &Self::Problem,
+        state: &mut State<Self::Problem>,
+        individuals: &mut [Individual<Self::Problem>],
+    ) {
+        let inner = match &mut self.inner {
+            Some(inner) if inner.shares_instance_with(problem) => inner,
+            inner => inner.insert(problem.evaluator()),
+        };
+        inner.evaluate(problem, state, individuals);
+    }
+}
+
+/// Runs `config` `runs` times on every problem of [`rwco_suite`] using mahf's
+/// [`par_experiment`], which writes the configuration and, if `log` is set, the logs
+/// of every run into `folder`.
+///
+/// Each run is evaluated by a [`SuiteEvaluator`] and seeded with its run number.
+pub fn par_experiment_rwco(
+    config: &Configuration<EngineeringOptimizationProblem>,
+    runs: u64,
+    folder: impl AsRef<Path>,
+    log: bool,
+) -> ExecResult<()> {
+    let problems = EngineeringOptimizationProblem::all()?;
+    par_experiment(
+        config,
+        |state| {
+            state.insert_evaluator(SuiteEvaluator::new());
+            Ok(())
+        },
+        &problems,
+        runs,
+        folder,
+        log,
+    )
+}
@@ -0,0 +1,289 @@
+//! Tables of precomputed objective values, for replaying evaluations without Python and
+//! comparing enoppy versions.
+
+use std::{fmt, fmt::Write as _, fs, marker::PhantomData, path::Path};
+
+use eyre::{ensure, eyre, WrapErr};
+use mahf::{problems::Evaluate, ExecResult, Individual, Problem, SingleObjective, State};
+use pyo3::Python;
+
+use crate::{EngineeringOptimizationEvaluator, EngineeringOptimizationProblem};
+
+/// An evaluator that looks up objective values in a table of precomputed
+/// `(solution, objective)` pairs instead of calling enoppy.
+///
+/// Each solution is assigned the objective of the nearest entry by Euclidean distance,
+/// and solutions without an entry of the same dimension are assigned [`f64::INFINITY`].
+/// This allows replaying recorded evaluations deterministically, e.g. in environments
+/// without Python.
+///
+/// # File format
+///
+/// Tables are stored as comma-separated values with one entry per line, where the last
+/// column is the objective value and all other columns are the solution.
+/// Empty lines and lines starting with `#` are ignored.
+///
+/// # Problems
+///
+/// The evaluator can be used for any problem with real-valued solutions and a single
+/// objective, e.g. a Rust stand-in for an [`EngineeringOptimizationProblem`] in tests.
+pub struct TableEvaluator<P = EngineeringOptimizationProblem> {
+    entries: Vec<(Vec<f64>, f64)>,
+    problem: PhantomData<fn() -> P>,
+}
+
+impl<P> Clone for TableEvaluator<P> {
+    fn clone(&self) -> Self {
+        Self::from_entries(self.entries.clone())
+    }
+}
+
+impl<P> fmt::Debug for TableEvaluator<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TableEvaluator")
+            .field("entries", &self.entries)
+            .finish()
+    }
+}
+
+impl<P> Default for TableEvaluator<P> {
+    fn default() -> Self {
+        Self::from_entries(Vec::new())
+    }
+}
+
+impl<P> TableEvaluator<P> {
+    pub fn from_entries(entries: Vec<(Vec<f64>, f64)>) -> Self {
+        Self {
+            entries,
+            problem: PhantomData,
+        }
+    }
+
+    /// Reads a table in the format described in the [type-level documentation](Self).
+    pub fn from_file(path: impl AsRef<Path>) -> ExecResult<Self> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .wrap_err_with(|| format!("failed to read `{}`", path.display()))?;
+
+        let entries = content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+            .map(|(i, line)| {
+                parse_entry(line).wrap_err_with(|| format!("invalid entry in line {}", i + 1))
+            })
+            .collect::<ExecResult<_>>()?;
+
+        Ok(Self::from_entries(entries))
+    }
+
+    /// Writes the table in the format described in the [type-level documentation](Self).
+    pub fn save(&self, path: impl AsRef<Path>) -> ExecResult<()> {
+        let path = path.as_ref();
+        let mut content = String::new();
+        for (solution, objective) in &self.entries {
+            for x in solution {
+                write!(content, "{x},")?;
+            }
+            writeln!(content, "{objective}")?;
+        }
+        fs::write(path, content).wrap_err_with(|| format!("failed to write `{}`", path.display()))
+    }
+
+    pub fn entries(&self) -> &[(Vec<f64>, f64)] {
+        &self.entries
+    }
+
+    /// Adds an entry, e.g. one evaluated with
+    /// [`EngineeringOptimizationEvaluator::evaluate_one`](crate::EngineeringOptimizationEvaluator::evaluate_one).
+    pub fn insert(&mut self, solution: Vec<f64>, objective: f64) {
+        self.entries.push((solution, objective));
+    }
+
+    /// Returns the objective value of the entry nearest to `solution`.
+    pub fn evaluate_one(&self, solution: &[f64]) -> f64 {
+        self.entries
+            .iter()
+            .filter(|(entry, _)| entry.len() == solution.len())
+            .map(|(entry, objective)| {
+                let distance: f64 = entry
+                    .iter()
+                    .zip(solution)
+                    .map(|(a, b)| (a - b).powi(2))
+                    .sum();
+                (distance, *objective)
+            })
+            .min_by(|(a, _), (b, _)| a.total_cmp(b))
+            .map_or(f64::INFINITY, |(_, objective)| objective)
+    }
+}
+
+fn parse_entry(line: &str) -> ExecResult<(Vec<f64>, f64)> {
+    let mut values = line
+        .split(',')
+        .map(|value| {
+            value
+                .trim()
+                .parse::<f64>()
+                .map_err(|err| eyre!("`{}` is not a number: {err}", value.trim()))
+        })
+        .collect::<ExecResult<Vec<_>>>()?;
+    let objective = values
+        .pop()
+        .ok_or_else(|| eyre!("expected an objective value"))?;
+    ensure!(
+        !values.is_empty(),
+        "expected a solution before the objective value"
+    );
+    Ok((values, objective))
+}
+
+impl<P> Evaluate for TableEvaluator<P>
+where
+    P: Problem<Encoding = Vec<f64>, Objective = SingleObjective>,
+{
+    type Problem = P;
+
+    fn evaluate(
+        &mut self,
+        _problem: &Self::Problem,
+        _state: &mut State<Self::Problem>,
+        individuals: &mut [Individual<Self::Problem>],
+    ) {
+        for individual in individuals.iter_mut() {
+            let objective = self.evaluate_one(individual.solution());
+            individual.set_objective(SingleObjective::try_from(objective).unwrap_or_default());
+        }
+    }
+}
+
+/// An entry of a baseline table whose objective value changed, see
+/// [`EngineeringOptimizationEvaluator::compare_versions`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Divergence {
+    pub solution: Vec<f64>,
+    /// The objective value stored in the baseline.
+    pub expected: f64,
+    /// The objective value returned by the installed enoppy version.
+    pub measured: f64,
+}
+
+impl EngineeringOptimizationEvaluator {
+    /// Evaluates the objective values of `solutions` into a baseline table for
+    /// [`Self::compare_versions`], e.g. to be [saved](TableEvaluator::save) before
+    /// updating enoppy.
+    ///
+    /// Objective values are stored without the penalty, as by [`Self::evaluate_full`].
+    pub fn record_baseline(&self, solutions: &[Vec<f64>]) -> TableEvaluator {
+        let entries = Python::with_gil(|_| {
+            solutions
+                .iter()
+                .map(|solution| (solution.clone(), self.evaluate_full(solution).objective))
+                .collect()
+        });
+        TableEvaluator::from_entries(entries)
+    }
+
+    /// Re-evaluates every entry of `baseline` and returns those whose objective value
+    /// differs by more than the relative `tolerance`, e.g. because enoppy changed a formula.
+    ///
+    /// Unlike [`Self::self_check`], this covers arbitrary points instead of only the known
+    /// optimum.
+    /// A baseline can be created with [`Self::record_baseline`].
+    pub fn compare_versions<P>(
+        &self,
+        baseline: &TableEvaluator<P>,
+        tolerance: f64,
+    ) -> Vec<Divergence> {
+        Python::with_gil(|_| {
+            baseline
+                .entries()
+                .iter()
+                .filter_map(|(solution, expected)| {
+                    let measured = self.evaluate_full(solution).objective;
+                    let matches = measured == *expected
+                        || (measured - expected).abs() <= tolerance * expected.abs().max(1.0);
+                    (!matches).then(|| Divergence {
+                        solution: solution.clone(),
+                        expected: *expected,
+                        measured,
+                    })
+                })
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    /// A problem that can be constructed without Python.
+    struct Sphere;
+
+    impl Problem for Sphere {
+        type Encoding = Vec<f64>;
+        type Objective = SingleObjective;
+
+        fn name(&self) -> &str {
+            "Sphere"
+        }
+    }
+
+    fn table() -> TableEvaluator<Sphere> {
+        TableEvaluator::from_entries(vec![
+            (vec![0.0, 0.0], 0.0),
+            (vec![1.0, 2.0], 5.0),
+            (vec![3.0], 9.0),
+        ])
+    }
+
+    #[test]
+    fn parse_entry_splits_solution_and_objective() {
+        assert_eq!(parse_entry("1, 2.5,-3").unwrap(), (vec![1.0, 2.5], -3.0));
+        assert!(parse_entry("1.5").is_err());
+        assert!(parse_entry("1,x,2").is_err());
+    }
+
+    #[test]
+    fn file_round_trip_keeps_entries() {
+        let path = env::temp_dir().join(format!("enop-rs-table-{}.csv", std::process::id()));
+        table().save(&path).unwrap();
+        let loaded = TableEvaluator::<Sphere>::from_file(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.entries(), table().entries());
+    }
+
+    #[test]
+    fn from_file_skips_comments_and_reports_lines() {
+        let path = env::temp_dir().join(format!("enop-rs-invalid-{}.csv", std::process::id()));
+        fs::write(&path, "# x0,x1,f\n\n1,2,5\n1,2,five\n").unwrap();
+        let err = TableEvaluator::<Sphere>::from_file(&path).unwrap_err();
+        fs::remove_file(&path).unwrap();
+        assert!(format!("{err:#}").contains("line 4"));
+    }
+
+    #[test]
+    fn lookup_uses_the_nearest_entry_of_the_same_dimension() {
+        let table = table();
+        assert_eq!(table.evaluate_one(&[0.9, 2.2]), 5.0);
+        assert_eq!(table.evaluate_one(&[0.1, -0.1]), 0.0);
+        assert_eq!(table.evaluate_one(&[100.0]), 9.0);
+        assert_eq!(table.evaluate_one(&[0.0, 0.0, 0.0]), f64::INFINITY);
+    }
+
+    #[test]
+    fn evaluate_assigns_objectives_to_individuals() {
+        let mut table = table();
+        let mut individuals = vec![
+            Individual::new_unevaluated(vec![1.1, 1.9]),
+            Individual::new_unevaluated(vec![7.0, 7.0, 7.0]),
+        ];
+        table.evaluate(&Sphere, &mut State::new(), &mut individuals);
+        assert_eq!(individuals[0].objective().value(), 5.0);
+        assert_eq!(individuals[1].objective().value(), f64::INFINITY);
+    }
+}
@@ -0,0 +1,78 @@
+//! Interrupting Python evaluations that exceed a time limit.
+
+use std::{
+    os::raw::c_long,
+    ptr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, RecvTimeoutError},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use log::warn;
+use pyo3::{ffi, PyResult, Python};
+
+/// Runs `f`, raising a `TimeoutError` in the current Python thread if it takes longer
+/// than `timeout`.
+///
+/// A watchdog thread acquires the GIL once the timeout expires and schedules the
+/// exception, which Python only raises between bytecode instructions.
+/// Native code that doesn't release the GIL, e.g. a long-running NumPy or SciPy call,
+/// is therefore only interrupted after it returns.
+pub(crate) fn with_timeout<T>(py: Python, timeout: Duration, f: impl FnOnce() -> T) -> T {
+    let thread_id = match thread_ident(py) {
+        Ok(thread_id) => thread_id,
+        Err(err) => {
+            warn!("running without timeout, failed to identify the Python thread: {err}");
+            return f();
+        }
+    };
+    let done = Arc::new(AtomicBool::new(false));
+    let (finished, wait) = mpsc::channel::<()>();
+
+    let watchdog_done = done.clone();
+    thread::spawn(move || {
+        if wait.recv_timeout(timeout) == Err(RecvTimeoutError::Timeout) {
+            Python::with_gil(|_| {
+                if !watchdog_done.load(Ordering::SeqCst) {
+                    // SAFETY: The GIL is held and the exception type is a valid static object.
+                    unsafe { ffi::PyThreadState_SetAsyncExc(thread_id, ffi::PyExc_TimeoutError) };
+                }
+            });
+        }
+    });
+
+    // Disarms the watchdog even if `f` panics, which would otherwise leave an exception
+    // scheduled for whatever code this thread runs next.
+    let _disarm = Disarm {
+        thread_id,
+        done,
+        _finished: finished,
+    };
+    f()
+}
+
+struct Disarm {
+    thread_id: c_long,
+    done: Arc<AtomicBool>,
+    _finished: mpsc::Sender<()>,
+}
+
+impl Drop for Disarm {
+    fn drop(&mut self) {
+        // The watchdog only schedules the exception while holding the GIL, which this
+        // thread holds now, so after setting `done` no further exception can be scheduled
+        // and a pending one can be cleared safely.
+        self.done.store(true, Ordering::SeqCst);
+        // SAFETY: The GIL is held, and a null exception clears any pending one.
+        unsafe { ffi::PyThreadState_SetAsyncExc(self.thread_id, ptr::null_mut()) };
+    }
+}
+
+fn thread_ident(py: Python) -> PyResult<c_long> {
+    let ident = py.import("threading")?.call_method0("get_ident")?;
+    Ok(ident.extract::<u64>()? as c_long)
+}
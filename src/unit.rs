@@ -0,0 +1,61 @@
+//! Evaluation in the unit hypercube.
+
+use std::ops::Range;
+
+use crate::{
+    from_unit, DynEngineeringProblem, EngineeringOptimizationEvaluator,
+    EngineeringOptimizationProblem,
+};
+
+/// An evaluator accepting solutions from `[0, 1]^dim`, which are rescaled to the
+/// domain before being evaluated, see [`EngineeringOptimizationProblem::from_unit`].
+///
+/// This allows optimizers to stay agnostic of the problem's bounds.
+#[derive(Clone)]
+pub struct UnitCubeEvaluator {
+    inner: EngineeringOptimizationEvaluator,
+}
+
+impl UnitCubeEvaluator {
+    pub fn new(problem: &EngineeringOptimizationProblem) -> Self {
+        Self::from_evaluator(problem.evaluator())
+    }
+
+    /// Wraps `inner`, keeping its configuration.
+    pub fn from_evaluator(inner: EngineeringOptimizationEvaluator) -> Self {
+        Self { inner }
+    }
+
+    pub fn into_inner(self) -> EngineeringOptimizationEvaluator {
+        self.inner
+    }
+
+    /// Evaluates the objective value of `u` rescaled to the domain, see
+    /// [`EngineeringOptimizationEvaluator::evaluate_one`].
+    pub fn evaluate_one(&self, u: &[f64]) -> f64 {
+        if u.len() != self.inner.domain.len() {
+            // Passed on unchanged to be rejected with a warning.
+            return self.inner.evaluate_one(u);
+        }
+        self.inner.evaluate_one(&from_unit(&self.inner.domain, u))
+    }
+}
+
+impl DynEngineeringProblem for UnitCubeEvaluator {
+    fn name(&self) -> &str {
+        &self.inner.name
+    }
+
+    fn dimension(&self) -> usize {
+        self.inner.domain.len()
+    }
+
+    fn domain(&self) -> Vec<Range<f64>> {
+        vec![0.0..1.0; self.inner.domain.len()]
+    }
+
+    /// Evaluates `solution` from the unit hypercube, see [`Self::evaluate_one`].
+    fn evaluate_one(&self, solution: &[f64]) -> f64 {
+        UnitCubeEvaluator::evaluate_one(self, solution)
+    }
+}